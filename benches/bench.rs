@@ -99,7 +99,8 @@ mod join {
         join_benches,
         vec_join_bench,
         array_join_bench,
-        tuple_join_bench
+        tuple_join_bench,
+        small_join_bench
     );
 
     fn vec_join_bench(c: &mut Criterion) {
@@ -134,6 +135,22 @@ mod join {
         });
     }
 
+    // Small joins don't allocate their wakers, see the poll-all mode of `WakerArray`.
+    fn small_join_bench(c: &mut Criterion) {
+        c.bench_function("array::join 2", |b| {
+            b.to_async(FuturesExecutor).iter(array_join::<2>)
+        });
+        c.bench_function("array::join 4", |b| {
+            b.to_async(FuturesExecutor).iter(array_join::<4>)
+        });
+        c.bench_function("array::join 8", |b| {
+            b.to_async(FuturesExecutor).iter(array_join::<8>)
+        });
+        c.bench_function("tuple::join 2", |b| {
+            b.to_async(FuturesExecutor).iter(tuple_join_2)
+        });
+    }
+
     async fn vec_join(max: usize) {
         let futures = futures_vec(max);
         let output = futures.join().await;
@@ -151,6 +168,12 @@ mod join {
         let output = futures.join().await;
         assert_eq!(output.0, ());
     }
+
+    async fn tuple_join_2() {
+        let [a, b] = futures_array::<2>();
+        let output = (a, b).join().await;
+        assert_eq!(output.0, ());
+    }
 }
 
 mod race {
@@ -93,9 +93,7 @@ impl PartialEq for PrioritizedWaker {
         self.0 == other.0
     }
 }
-impl Eq for PrioritizedWaker {
-    fn assert_receiver_is_total_eq(&self) {}
-}
+impl Eq for PrioritizedWaker {}
 impl PartialOrd for PrioritizedWaker {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
@@ -1,13 +1,11 @@
 use async_std::io::prelude::*;
-use futures::future::TryFutureExt;
 use futures_concurrency::prelude::*;
-use futures_time::prelude::*;
 
 use async_std::io;
 use async_std::net::TcpStream;
-use futures::channel::oneshot;
-use futures_time::time::Duration;
+use async_std::task;
 use std::error::Error;
+use std::time::Duration;
 
 #[async_std::main]
 async fn main() -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
@@ -29,21 +27,13 @@ async fn open_tcp_socket(
     port: u16,
     attempts: u64,
 ) -> Result<TcpStream, Vec<io::Error>> {
-    let (mut sender, mut receiver) = oneshot::channel();
-    let mut futures = Vec::with_capacity(attempts as usize);
+    let futures: Vec<_> = (0..attempts)
+        .map(|_| TcpStream::connect((addr, port)))
+        .collect();
 
-    for attempt in 0..attempts {
-        // Start a next attempt if the previous one finishes, or timeout expires.
-        let tcp = TcpStream::connect((addr, port));
-        let start_event = receiver.timeout(Duration::from_secs(attempt));
-        futures.push(tcp.delay(start_event).map_err(|err| {
-            // If the socket fails, start the next attempt
-            let _ = sender.send(());
-            err
-        }));
-        (sender, receiver) = oneshot::channel();
-    }
-
-    // Start connecting. If an attempt succeeds, cancel all others attempts.
-    Ok(futures.race_ok().await?)
+    // Start a next attempt if the previous one fails, or the timeout expires.
+    // If an attempt succeeds, cancel all others attempts.
+    futures
+        .race_ok_staggered(task::sleep, Duration::from_secs(1))
+        .await
 }
@@ -1,3 +1,6 @@
+use super::super::fairness::{Fairness, FairnessState};
+use super::super::timeout::{private, PartialFuture};
+use super::KeepOutput;
 use crate::utils::{self, WakerArray};
 
 use core::array;
@@ -8,6 +11,8 @@ use core::mem::MaybeUninit;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
+use futures_core::future::FusedFuture;
+use futures_core::stream::{FusedStream, Stream};
 use pin_project::{pin_project, pinned_drop};
 
 /// A trait for making CombinatorArray behave as Join/TryJoin/Race/RaceOk.
@@ -32,6 +37,17 @@ where
     /// Called when all subfutures are completed and none cause the combinator to return early.
     /// The argument is an array of the kept item from each subfuture.
     fn when_completed_arr(arr: [Self::StoredItem; N]) -> Self::Output;
+
+    /// Whether every subfuture normally runs to completion, as for Join and
+    /// TryJoin. Small combinators like that poll all subfutures on every
+    /// wakeup, instead of keeping track of which ones were woken.
+    const AWAITS_ALL: bool = false;
+
+    /// Whether the stream from `into_stream` should end after yielding this
+    /// output, as TryJoin's does after the first error.
+    fn ends_stream(_output: &Fut::Output) -> bool {
+        false
+    }
 }
 
 #[must_use = "futures do nothing unless you `.await` or poll them"]
@@ -53,6 +69,10 @@ where
     /// A temporary buffer for indices that have woken.
     /// The data here don't have to persist between each `poll`.
     awake_list_buffer: [usize; N],
+    /// The order in which woken subfutures are polled.
+    fairness: FairnessState,
+    /// Futures should not be polled after completing.
+    done: bool,
     #[pin]
     futures: [Fut; N],
 }
@@ -67,14 +87,26 @@ where
         CombinatorArray {
             behavior: PhantomData,
             pending: N,
-            wakers: WakerArray::new(),
+            wakers: if B::AWAITS_ALL {
+                WakerArray::for_join()
+            } else {
+                WakerArray::new()
+            },
             items: array::from_fn(|_| MaybeUninit::uninit()),
             filled: [false; N],
             // TODO: this is a temporary buffer so it can be MaybeUninit.
             awake_list_buffer: [0; N],
+            fairness: FairnessState::default(),
+            done: false,
             futures,
         }
     }
+
+    /// Set the order in which subfutures woken at the same time are polled.
+    pub(crate) fn with_fairness(mut self, fairness: Fairness) -> Self {
+        self.fairness = FairnessState::new(fairness);
+        self
+    }
 }
 
 impl<Fut, B, const N: usize> fmt::Debug for CombinatorArray<Fut, B, N>
@@ -98,21 +130,20 @@ where
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let mut this = self.project();
 
-        // If this.pending == 0, the future is done.
-        assert!(
-            N == 0 || *this.pending > 0,
-            "Futures must not be polled after completing"
-        );
+        assert!(!*this.done, "Futures must not be polled after completing");
 
         let num_awake = {
             // Lock the awakeness Mutex.
             let mut awakeness = this.wakers.awakeness();
             awakeness.set_parent_waker(cx.waker());
 
-            // Copy the list of indices that have woken..
-            let awake_list = awakeness.awake_list();
+            // Copy the list of indices that have woken, in the order they should be polled.
+            let awake_list = awakeness.awake_list_mut();
             let num_awake = awake_list.len();
-            this.awake_list_buffer[..num_awake].copy_from_slice(awake_list);
+            let ordered = this.fairness.order(awake_list, N);
+            for (slot, &idx) in this.awake_list_buffer.iter_mut().zip(ordered) {
+                *slot = idx;
+            }
 
             // Clear the list.
             awakeness.clear();
@@ -140,7 +171,10 @@ where
                         *this.pending -= 1;
                     }
                     // Early return.
-                    Err(ret) => return Poll::Ready(ret),
+                    Err(ret) => {
+                        *this.done = true;
+                        return Poll::Ready(ret);
+                    }
                 }
             }
         }
@@ -160,13 +194,10 @@ where
 
             // SAFETY: this.pending is only decremented when an item slot is filled.
             // pending reaching 0 means the entire items array is filled.
-            //
-            // For N > 0, we can only enter this if block once (because the assert at the top),
-            // so it is safe to take the data.
-            // For N == 0, we can enter this if block many times (in case of poll-after-done),
-            // but then the items array is empty anyway so we're fine.
+            // We mark the future as done below, so we only get here once.
             let items = unsafe { utils::array_assume_init(items) };
 
+            *this.done = true;
             // Let the Behavior do any final transformation.
             // For example, TryJoin would wrap the whole thing in Ok.
             Poll::Ready(B::when_completed_arr(items))
@@ -176,6 +207,50 @@ where
     }
 }
 
+impl<Fut, B, const N: usize> FusedFuture for CombinatorArray<Fut, B, N>
+where
+    Fut: Future,
+    B: CombinatorBehaviorArray<Fut, N>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<Fut, B, const N: usize> private::Sealed for CombinatorArray<Fut, B, N>
+where
+    Fut: Future,
+    B: CombinatorBehaviorArray<Fut, N>,
+{
+}
+
+impl<Fut, B, const N: usize> PartialFuture for CombinatorArray<Fut, B, N>
+where
+    Fut: Future,
+    B: CombinatorBehaviorArray<Fut, N>,
+{
+    type Partial = [Option<B::StoredItem>; N];
+
+    fn take_partial(self: Pin<&mut Self>) -> Self::Partial {
+        let this = self.project();
+
+        // Every item we take out is marked unfilled again, so keep the
+        // `filled`/`pending` invariant intact.
+        *this.pending = N;
+        let filled = this.filled;
+        let items = this.items;
+        array::from_fn(|idx| {
+            if core::mem::replace(&mut filled[idx], false) {
+                // SAFETY: filled is only set to true for initialized items,
+                // and we've just unset it so the item won't be read again.
+                Some(unsafe { items[idx].assume_init_read() })
+            } else {
+                None
+            }
+        })
+    }
+}
+
 /// Drop the already initialized values on cancellation.
 #[pinned_drop]
 impl<Fut, B, const N: usize> PinnedDrop for CombinatorArray<Fut, B, N>
@@ -194,3 +269,185 @@ where
         }
     }
 }
+
+/// A stream which yields the output of each subfuture as soon as it completes.
+///
+/// This `struct` is created by the `into_stream` method on the array
+/// combinators, such as [`array::Join`][crate::array::Join]. See its
+/// documentation for more.
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct CombinatorArrayStream<Fut, B, const N: usize>
+where
+    Fut: Future,
+    B: CombinatorBehaviorArray<Fut, N>,
+{
+    /// The `items` of the combinator are only filled when the outputs are
+    /// kept, see `keep_outputs`.
+    #[pin]
+    inner: CombinatorArray<Fut, B, N>,
+    /// Which subfutures have completed, and must not be polled again.
+    completed: [bool; N],
+    /// Number of subfutures which haven't completed yet.
+    pending: usize,
+    /// Copies every output into the combinator, when the outputs are kept.
+    keep: Option<KeepOutput<Fut::Output>>,
+    /// The output the combinator returned early with, if any.
+    output: Option<B::Output>,
+    /// Streams should not be polled after complete.
+    done: bool,
+}
+
+impl<Fut, B, const N: usize> CombinatorArrayStream<Fut, B, N>
+where
+    Fut: Future,
+    B: CombinatorBehaviorArray<Fut, N>,
+{
+    pub(crate) fn new(inner: CombinatorArray<Fut, B, N>) -> Self {
+        Self {
+            inner,
+            completed: [false; N],
+            pending: N,
+            keep: None,
+            output: None,
+            done: false,
+        }
+    }
+
+    /// Keep a copy of every output, so that once the stream is exhausted it
+    /// can be [finished][Self::finish] into the output of the combinator.
+    pub fn keep_outputs(mut self) -> Self
+    where
+        Fut::Output: Clone,
+    {
+        self.keep = Some(Fut::Output::clone);
+        self
+    }
+
+    /// Take the output the combinator would have resolved to, such as the
+    /// array of all outputs in order for a join.
+    ///
+    /// If the combinator would have returned early, such as on the first
+    /// error of a try-join, that output is returned right away. Otherwise
+    /// this returns `None` until every subfuture has completed, and always
+    /// if the outputs weren't kept with [`keep_outputs`][Self::keep_outputs].
+    /// The output can only be taken once.
+    pub fn finish(self: Pin<&mut Self>) -> Option<B::Output> {
+        let this = self.project();
+        if let Some(output) = this.output.take() {
+            return Some(output);
+        }
+
+        let inner = this.inner.project();
+        if this.keep.is_none() || *inner.pending != 0 || *inner.done {
+            return None;
+        }
+        inner.filled.fill(false);
+        *inner.done = true;
+
+        let mut items = array::from_fn(|_| MaybeUninit::uninit());
+        core::mem::swap(inner.items, &mut items);
+
+        // SAFETY: the combinator's pending count is only decremented when an
+        // item slot is filled, so all of them are. We've marked the slots
+        // unfilled and the combinator done, so they won't be read again.
+        let items = unsafe { utils::array_assume_init(items) };
+        Some(B::when_completed_arr(items))
+    }
+}
+
+impl<Fut, B, const N: usize> fmt::Debug for CombinatorArrayStream<Fut, B, N>
+where
+    Fut: Future + fmt::Debug,
+    B: CombinatorBehaviorArray<Fut, N>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<Fut, B, const N: usize> Stream for CombinatorArrayStream<Fut, B, N>
+where
+    Fut: Future,
+    B: CombinatorBehaviorArray<Fut, N>,
+{
+    type Item = (usize, Fut::Output);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        assert!(!*this.done, "Stream should not be polled after completing");
+
+        if *this.pending == 0 {
+            *this.done = true;
+            return Poll::Ready(None);
+        }
+
+        let mut inner = this.inner.project();
+
+        let num_awake = {
+            let mut awakeness = inner.wakers.awakeness();
+            awakeness.set_parent_waker(cx.waker());
+            let awake_list = awakeness.awake_list_mut();
+            let num_awake = awake_list.len();
+            let ordered = inner.fairness.order(awake_list, N);
+            for (slot, &idx) in inner.awake_list_buffer.iter_mut().zip(ordered) {
+                *slot = idx;
+            }
+            awakeness.clear();
+            num_awake
+        };
+
+        for (pos, &idx) in inner.awake_list_buffer.iter().take(num_awake).enumerate() {
+            let completed = &mut this.completed[idx];
+            if *completed {
+                continue;
+            }
+            let fut = utils::get_pin_mut(inner.futures.as_mut(), idx).unwrap();
+            let mut cx = Context::from_waker(inner.wakers.get(idx).unwrap());
+            if let Poll::Ready(value) = fut.poll(&mut cx) {
+                *completed = true;
+                *this.pending -= 1;
+
+                if let Some(keep) = this.keep {
+                    match B::maybe_return(idx, keep(&value)) {
+                        Ok(store) => {
+                            inner.items[idx].write(store);
+                            inner.filled[idx] = true;
+                            *inner.pending -= 1;
+                        }
+                        // Like the future, we're done once we return early,
+                        // so stop keeping the outputs which come after.
+                        Err(ret) => {
+                            *this.output = Some(ret);
+                            *this.keep = None;
+                        }
+                    }
+                }
+                if B::ends_stream(&value) {
+                    // Leave the remaining subfutures, the stream ends here.
+                    *this.pending = 0;
+                }
+
+                // We're returning before we got to the remaining awake subfutures.
+                // Wake them again so they are polled next time.
+                for &idx in &inner.awake_list_buffer[pos + 1..num_awake] {
+                    inner.wakers.get(idx).unwrap().wake_by_ref();
+                }
+                return Poll::Ready(Some((idx, value)));
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<Fut, B, const N: usize> FusedStream for CombinatorArrayStream<Fut, B, N>
+where
+    Fut: Future,
+    B: CombinatorBehaviorArray<Fut, N>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
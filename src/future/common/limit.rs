@@ -0,0 +1,171 @@
+use super::CombinatorBehaviorVec;
+use crate::utils::{self, WakerVec};
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::future::FusedFuture;
+use pin_project::pin_project;
+
+type FutureOf<I> = <<I as Iterator>::Item as IntoFuture>::IntoFuture;
+
+/// Like [super::CombinatorVec], but only keeps a limited number of futures in
+/// flight at any given time.
+///
+/// The futures in flight live in a fixed window of slots, each of which has
+/// its own waker. Once a future completes, its slot is reused for the next
+/// future from the iterator.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct CombinatorLimit<I, B>
+where
+    I: Iterator,
+    I::Item: IntoFuture,
+    B: CombinatorBehaviorVec<FutureOf<I>>,
+{
+    behavior: PhantomData<B>,
+    /// The futures which haven't been started yet.
+    /// `None` once the iterator is exhausted.
+    iter: Option<I>,
+    /// The stored items from each future, in iteration order.
+    items: Vec<Option<B::StoredItem>>,
+    /// The slots which don't currently hold a future.
+    vacant: Vec<usize>,
+    /// The index into `items` of the future in each slot.
+    slot_index: Vec<usize>,
+    wakers: WakerVec,
+    awake_list_buffer: Vec<usize>,
+    done: bool,
+    /// The futures in flight. The length of this vector never changes after
+    /// construction, so the slots can be pinned in place.
+    #[pin]
+    slots: Vec<Option<FutureOf<I>>>,
+}
+
+impl<I, B> CombinatorLimit<I, B>
+where
+    I: Iterator,
+    I::Item: IntoFuture,
+    B: CombinatorBehaviorVec<FutureOf<I>>,
+{
+    pub(crate) fn new(iter: I, limit: usize) -> Self {
+        assert!(limit > 0, "the concurrency limit must be greater than zero");
+        Self {
+            behavior: PhantomData,
+            iter: Some(iter),
+            items: Vec::new(),
+            vacant: (0..limit).rev().collect(),
+            slot_index: alloc::vec![0; limit],
+            wakers: WakerVec::new(limit),
+            awake_list_buffer: Vec::new(),
+            done: false,
+            slots: core::iter::repeat_with(|| None).take(limit).collect(),
+        }
+    }
+}
+
+impl<I, B> fmt::Debug for CombinatorLimit<I, B>
+where
+    I: Iterator,
+    I::Item: IntoFuture,
+    FutureOf<I>: fmt::Debug,
+    B: CombinatorBehaviorVec<FutureOf<I>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.slots.iter().flatten()).finish()
+    }
+}
+
+impl<I, B> Future for CombinatorLimit<I, B>
+where
+    I: Iterator,
+    I::Item: IntoFuture,
+    B: CombinatorBehaviorVec<FutureOf<I>>,
+{
+    type Output = B::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        assert!(!*this.done, "Futures must not be polled after completing");
+
+        loop {
+            // Fill up the vacant slots with new futures.
+            while let Some(iter) = this.iter.as_mut() {
+                let Some(&slot) = this.vacant.last() else {
+                    break;
+                };
+                let Some(fut) = iter.next() else {
+                    *this.iter = None;
+                    break;
+                };
+                this.vacant.pop();
+                utils::get_pin_mut_from_vec(this.slots.as_mut(), slot)
+                    .unwrap()
+                    .set(Some(fut.into_future()));
+                this.slot_index[slot] = this.items.len();
+                this.items.push(None);
+                this.wakers.get(slot).unwrap().wake_by_ref();
+            }
+
+            {
+                let mut awakeness = this.wakers.awakeness();
+                awakeness.set_parent_waker(cx.waker());
+                this.awake_list_buffer.clone_from(awakeness.awake_list());
+                awakeness.clear();
+            }
+
+            let mut completed = false;
+            for &slot in this.awake_list_buffer.iter() {
+                let mut fut = utils::get_pin_mut_from_vec(this.slots.as_mut(), slot).unwrap();
+                let Some(inner) = fut.as_mut().as_pin_mut() else {
+                    continue;
+                };
+                let mut cx = Context::from_waker(this.wakers.get(slot).unwrap());
+                if let Poll::Ready(value) = inner.poll(&mut cx) {
+                    // Drop the future in place, and free up its slot.
+                    fut.set(None);
+                    this.vacant.push(slot);
+                    completed = true;
+
+                    let idx = this.slot_index[slot];
+                    match B::maybe_return(idx, value) {
+                        Ok(store) => this.items[idx] = Some(store),
+                        Err(ret) => {
+                            *this.done = true;
+                            return Poll::Ready(ret);
+                        }
+                    }
+                }
+            }
+
+            // If futures completed and there are more to start, go again.
+            if !(completed && this.iter.is_some()) {
+                break;
+            }
+        }
+
+        if this.iter.is_none() && this.vacant.len() == this.slots.len() {
+            *this.done = true;
+            let items = this.items.drain(..).map(Option::unwrap).collect();
+            Poll::Ready(B::when_completed_vec(items))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<I, B> FusedFuture for CombinatorLimit<I, B>
+where
+    I: Iterator,
+    I::Item: IntoFuture,
+    B: CombinatorBehaviorVec<FutureOf<I>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
@@ -1,9 +1,18 @@
 mod array;
+#[cfg(feature = "alloc")]
+mod limit;
 mod tuple;
+#[cfg(feature = "alloc")]
 mod vec;
 
-pub(crate) use array::{CombinatorArray, CombinatorBehaviorArray};
+pub(crate) use array::{CombinatorArray, CombinatorArrayStream, CombinatorBehaviorArray};
+#[cfg(feature = "alloc")]
+pub(crate) use limit::CombinatorLimit;
 pub(crate) use tuple::{CombineTuple, MapResult};
-pub(crate) use vec::{CombinatorBehaviorVec, CombinatorVec};
+#[cfg(feature = "alloc")]
+pub(crate) use vec::{CombinatorBehaviorVec, CombinatorVec, CombinatorVecStream};
 
 pub use tuple::select_types;
+
+/// Copies an output, so the completion streams can keep it for `finish`.
+type KeepOutput<T> = fn(&T) -> T;
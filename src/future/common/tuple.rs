@@ -1,3 +1,4 @@
+use super::super::fairness::{Fairness, FairnessState};
 use crate::utils::WakerArray;
 
 use core::fmt::{self, Debug};
@@ -7,7 +8,7 @@ use core::mem::MaybeUninit;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use futures_core::TryFuture;
+use futures_core::future::{FusedFuture, TryFuture};
 
 // Basically we're implementing try_join here.
 // All the other combinators can be derived from try_join by wrapping subfutures
@@ -26,6 +27,10 @@ pub trait CombineTuple {
 pub trait MapResult<IntermediateResult> {
     type FinalResult;
     fn to_final_result(result: IntermediateResult) -> Self::FinalResult;
+
+    /// Whether every subfuture normally runs to completion, as for Join and
+    /// TryJoin. See `CombinatorBehaviorArray::AWAITS_ALL`.
+    const AWAITS_ALL: bool = false;
 }
 
 macro_rules! impl_common_tuple {
@@ -44,11 +49,20 @@ macro_rules! impl_common_tuple {
             wakers: WakerArray<{$mod_name::LEN}>,
             filled: [bool; $mod_name::LEN],
             awake_list_buffer: [usize; $mod_name::LEN],
+            fairness: FairnessState,
+            done: bool,
             #[pin]
             futures: $mod_name::Futures<$($F,)+>,
             phantom: PhantomData<B>
         }
 
+        impl<B, $($F: TryFuture),+> $StructName<B, $($F),+> {
+            pub(crate) fn with_fairness(mut self, fairness: Fairness) -> Self {
+                self.fairness = FairnessState::new(fairness);
+                self
+            }
+        }
+
         impl<B, $($F),+> Debug for $StructName<B, $($F),+>
         where $(
             $F: TryFuture + Debug,
@@ -61,17 +75,24 @@ macro_rules! impl_common_tuple {
         }
 
 		impl<B, $($F),+> CombineTuple for (($($F,)+), B)
-		where $(
-            $F: TryFuture,
-        )+ {
+		where
+            B: MapResult<Result<($($F::Ok,)+), select_types::$SelectedFrom<$($F::Error),+>>>,
+            $($F: TryFuture,)+
+        {
 			type Combined = $StructName<B, $($F,)+>;
 			fn combine(self) -> Self::Combined {
 				$StructName {
                     filled: [false; $mod_name::LEN],
                     items: ($(MaybeUninit::<$F::Ok>::uninit(),)+),
-                    wakers: WakerArray::new(),
+                    wakers: if B::AWAITS_ALL {
+                        WakerArray::for_join()
+                    } else {
+                        WakerArray::new()
+                    },
                     pending: $mod_name::LEN,
                     awake_list_buffer: [0; $mod_name::LEN],
+                    fairness: FairnessState::default(),
+                    done: false,
                     futures: $mod_name::Futures {$($F: self.0.$idx,)+},
                     phantom: PhantomData
                 }
@@ -88,19 +109,19 @@ macro_rules! impl_common_tuple {
                 self: Pin<&mut Self>, cx: &mut Context<'_>
             ) -> Poll<Self::Output> {
                 let mut this = self.project();
-                assert!(
-                    *this.pending > 0,
-                    "Futures must not be polled after completing"
-                );
+                assert!(!*this.done, "Futures must not be polled after completing");
 
                 let mut futures = this.futures.project();
 
                 let num_awake = {
                     let mut awakeness = this.wakers.awakeness();
                     awakeness.set_parent_waker(cx.waker());
-                    let awake_list = awakeness.awake_list();
+                    let awake_list = awakeness.awake_list_mut();
                     let num_awake = awake_list.len();
-                    this.awake_list_buffer[..num_awake].copy_from_slice(awake_list);
+                    let ordered = this.fairness.order(awake_list, $mod_name::LEN);
+                    for (slot, &idx) in this.awake_list_buffer.iter_mut().zip(ordered) {
+                        *slot = idx;
+                    }
                     awakeness.clear();
                     num_awake
                 };
@@ -120,6 +141,7 @@ macro_rules! impl_common_tuple {
 										Err(ret) => {
                                             let ret = Err(select_types::$SelectedFrom::$F(ret));
                                             let ret = B::to_final_result(ret);
+                                            *this.done = true;
 											return Poll::Ready(ret);
 										},
 										Ok(store) => {
@@ -152,6 +174,7 @@ macro_rules! impl_common_tuple {
                         // filled, which means we're ready to take the data and assume it's initialized.
                         unsafe { ($($F.assume_init(),)+) }
                     };
+                    *this.done = true;
                     Poll::Ready(B::to_final_result(Ok(out)))
                 }
                 else {
@@ -160,6 +183,12 @@ macro_rules! impl_common_tuple {
             }
         }
 
+        impl<B: MapResult<Result<($($F::Ok,)+), select_types::$SelectedFrom<$($F::Error),+>>>, $($F: TryFuture),+> FusedFuture for $StructName<B, $($F),+> {
+            fn is_terminated(&self) -> bool {
+                self.done
+            }
+        }
+
         #[pin_project::pinned_drop]
         impl<B, $($F: TryFuture),+> PinnedDrop for $StructName<B, $($F),+> {
             fn drop(self: Pin<&mut Self>) {
@@ -197,7 +226,7 @@ pub mod select_types {
         ($TypeName:ident, $($V:ident=$idx:literal)+) => {
             /// Enum representing a single field in a tuple.
             /// Variants are ordered from first field to last field.
-            #[derive(Debug)]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
             pub enum $TypeName<$($V),+> {
                 $(
                     /// A tuple field.
@@ -1,13 +1,18 @@
+use super::super::fairness::{Fairness, FairnessState};
+use super::super::timeout::{private, PartialFuture};
+use super::KeepOutput;
 use crate::utils::{self, WakerVec};
 
+use alloc::vec::Vec;
 use core::fmt;
 use core::future::Future;
 use core::mem::MaybeUninit;
 use core::pin::Pin;
 use core::task::{Context, Poll};
-use std::vec::Vec;
 
 use bitvec::vec::BitVec;
+use futures_core::future::FusedFuture;
+use futures_core::stream::{FusedStream, Stream};
 use pin_project::{pin_project, pinned_drop};
 
 // For code comments, see the array module.
@@ -22,6 +27,9 @@ where
     type StoredItem;
     fn maybe_return(idx: usize, res: Fut::Output) -> Result<Self::StoredItem, Self::Output>;
     fn when_completed_vec(vec: Vec<Self::StoredItem>) -> Self::Output;
+    fn ends_stream(_output: &Fut::Output) -> bool {
+        false
+    }
 }
 
 /// See [super::CombinatorArray] for documentation.
@@ -37,6 +45,9 @@ where
     wakers: WakerVec,
     filled: BitVec,
     awake_list_buffer: Vec<usize>,
+    fairness: FairnessState,
+    /// Futures should not be polled after completing.
+    done: bool,
     #[pin]
     futures: Vec<Fut>,
 }
@@ -50,15 +61,22 @@ where
         let len = futures.len();
         CombinatorVec {
             pending: len,
-            items: std::iter::repeat_with(MaybeUninit::uninit)
+            items: core::iter::repeat_with(MaybeUninit::uninit)
                 .take(len)
                 .collect(),
             wakers: WakerVec::new(len),
             filled: BitVec::repeat(false, len),
             awake_list_buffer: Vec::new(),
+            fairness: FairnessState::default(),
+            done: false,
             futures,
         }
     }
+
+    pub(crate) fn with_fairness(mut self, fairness: Fairness) -> Self {
+        self.fairness = FairnessState::new(fairness);
+        self
+    }
 }
 
 impl<Fut, B> fmt::Debug for CombinatorVec<Fut, B>
@@ -81,16 +99,16 @@ where
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let mut this = self.project();
 
-        assert!(
-            *this.pending > 0 || this.items.is_empty(),
-            "Futures must not be polled after completing"
-        );
+        assert!(!*this.done, "Futures must not be polled after completing");
 
         {
             let mut awakeness = this.wakers.awakeness();
             awakeness.set_parent_waker(cx.waker());
 
-            this.awake_list_buffer.clone_from(awakeness.awake_list());
+            let len = this.items.len();
+            this.awake_list_buffer.clear();
+            this.awake_list_buffer
+                .extend(this.fairness.order(awakeness.awake_list_mut(), len));
 
             awakeness.clear();
         }
@@ -109,6 +127,7 @@ where
                         *this.pending -= 1;
                     }
                     Err(ret) => {
+                        *this.done = true;
                         return Poll::Ready(ret);
                     }
                 }
@@ -124,16 +143,13 @@ where
 
             // SAFETY: this.pending is only decremented when an item slot is filled.
             // pending reaching 0 means the entire items array is filled.
-            //
-            // For len > 0, we can only enter this if block once (because the assert at the top),
-            // so it is safe to take the data.
-            // For len == 0, we can enter this if block many times (in case of poll-after-done),
-            // but then the items array is empty anyway so we're fine.
+            // We mark the future as done below, so we only get here once.
             let items = unsafe {
                 let items = core::mem::take(this.items);
                 core::mem::transmute::<Vec<MaybeUninit<B::StoredItem>>, Vec<B::StoredItem>>(items)
             };
 
+            *this.done = true;
             Poll::Ready(B::when_completed_vec(items))
         } else {
             Poll::Pending
@@ -141,6 +157,50 @@ where
     }
 }
 
+impl<Fut, B> FusedFuture for CombinatorVec<Fut, B>
+where
+    Fut: Future,
+    B: CombinatorBehaviorVec<Fut>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<Fut, B> private::Sealed for CombinatorVec<Fut, B>
+where
+    Fut: Future,
+    B: CombinatorBehaviorVec<Fut>,
+{
+}
+
+impl<Fut, B> PartialFuture for CombinatorVec<Fut, B>
+where
+    Fut: Future,
+    B: CombinatorBehaviorVec<Fut>,
+{
+    type Partial = Vec<Option<B::StoredItem>>;
+
+    fn take_partial(self: Pin<&mut Self>) -> Self::Partial {
+        let this = self.project();
+        *this.pending = this.items.len();
+        let filled = this.filled;
+        this.items
+            .iter_mut()
+            .enumerate()
+            .map(|(idx, item)| {
+                if filled.replace(idx, false) {
+                    // SAFETY: filled is only set to true for initialized items,
+                    // and we've just unset it so the item won't be read again.
+                    Some(unsafe { item.assume_init_read() })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
 /// Drop the already initialized values on cancellation.
 #[pinned_drop]
 impl<Fut, B> PinnedDrop for CombinatorVec<Fut, B>
@@ -159,3 +219,172 @@ where
         }
     }
 }
+
+/// A stream which yields the output of each subfuture as soon as it completes.
+///
+/// This `struct` is created by the `into_stream` method on the vec
+/// combinators, such as [`vec::Join`][crate::vec::Join]. See its
+/// documentation for more.
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct CombinatorVecStream<Fut, B>
+where
+    Fut: Future,
+    B: CombinatorBehaviorVec<Fut>,
+{
+    #[pin]
+    inner: CombinatorVec<Fut, B>,
+    completed: BitVec,
+    pending: usize,
+    keep: Option<KeepOutput<Fut::Output>>,
+    output: Option<B::Output>,
+    done: bool,
+}
+
+impl<Fut, B> CombinatorVecStream<Fut, B>
+where
+    Fut: Future,
+    B: CombinatorBehaviorVec<Fut>,
+{
+    pub(crate) fn new(inner: CombinatorVec<Fut, B>) -> Self {
+        let len = inner.futures.len();
+        Self {
+            inner,
+            completed: BitVec::repeat(false, len),
+            pending: len,
+            keep: None,
+            output: None,
+            done: false,
+        }
+    }
+
+    /// Keep a copy of every output, so that once the stream is exhausted it
+    /// can be [finished][Self::finish] into the output of the combinator.
+    pub fn keep_outputs(mut self) -> Self
+    where
+        Fut::Output: Clone,
+    {
+        self.keep = Some(Fut::Output::clone);
+        self
+    }
+
+    /// Take the output the combinator would have resolved to, such as the
+    /// vec of all outputs in order for a join.
+    ///
+    /// If the combinator would have returned early, such as on the first
+    /// error of a try-join, that output is returned right away. Otherwise
+    /// this returns `None` until every subfuture has completed, and always
+    /// if the outputs weren't kept with [`keep_outputs`][Self::keep_outputs].
+    /// The output can only be taken once.
+    pub fn finish(self: Pin<&mut Self>) -> Option<B::Output> {
+        let this = self.project();
+        if let Some(output) = this.output.take() {
+            return Some(output);
+        }
+
+        let inner = this.inner.project();
+        if this.keep.is_none() || *inner.pending != 0 || *inner.done {
+            return None;
+        }
+        inner.filled.fill(false);
+        *inner.done = true;
+
+        // SAFETY: see the `Future` impl of `CombinatorVec`.
+        let items = unsafe {
+            let items = core::mem::take(inner.items);
+            core::mem::transmute::<Vec<MaybeUninit<B::StoredItem>>, Vec<B::StoredItem>>(items)
+        };
+        Some(B::when_completed_vec(items))
+    }
+}
+
+impl<Fut, B> fmt::Debug for CombinatorVecStream<Fut, B>
+where
+    Fut: Future + fmt::Debug,
+    B: CombinatorBehaviorVec<Fut>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<Fut, B> Stream for CombinatorVecStream<Fut, B>
+where
+    Fut: Future,
+    B: CombinatorBehaviorVec<Fut>,
+{
+    type Item = (usize, Fut::Output);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        assert!(!*this.done, "Stream should not be polled after completing");
+
+        if *this.pending == 0 {
+            *this.done = true;
+            return Poll::Ready(None);
+        }
+
+        let mut inner = this.inner.project();
+
+        {
+            let mut awakeness = inner.wakers.awakeness();
+            awakeness.set_parent_waker(cx.waker());
+            let len = this.completed.len();
+            inner.awake_list_buffer.clear();
+            inner
+                .awake_list_buffer
+                .extend(inner.fairness.order(awakeness.awake_list_mut(), len));
+            awakeness.clear();
+        }
+
+        for pos in 0..inner.awake_list_buffer.len() {
+            let idx = inner.awake_list_buffer[pos];
+            if this.completed[idx] {
+                continue;
+            }
+            let fut = utils::get_pin_mut_from_vec(inner.futures.as_mut(), idx).unwrap();
+            let mut cx = Context::from_waker(inner.wakers.get(idx).unwrap());
+            if let Poll::Ready(value) = fut.poll(&mut cx) {
+                this.completed.set(idx, true);
+                *this.pending -= 1;
+
+                if let Some(keep) = this.keep {
+                    match B::maybe_return(idx, keep(&value)) {
+                        Ok(store) => {
+                            inner.items[idx].write(store);
+                            inner.filled.set(idx, true);
+                            *inner.pending -= 1;
+                        }
+                        Err(ret) => {
+                            *this.output = Some(ret);
+                            *this.keep = None;
+                        }
+                    }
+                }
+                if B::ends_stream(&value) {
+                    *this.pending = 0;
+                }
+
+                for &idx in &inner.awake_list_buffer[pos + 1..] {
+                    inner.wakers.get(idx).unwrap().wake_by_ref();
+                }
+                inner.awake_list_buffer.clear();
+                return Poll::Ready(Some((idx, value)));
+            }
+        }
+        inner.awake_list_buffer.clear();
+
+        Poll::Pending
+    }
+}
+
+impl<Fut, B> FusedStream for CombinatorVecStream<Fut, B>
+where
+    Fut: Future,
+    B: CombinatorBehaviorVec<Fut>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
@@ -0,0 +1,199 @@
+/// The order in which futures or streams which are ready at the same time are
+/// polled.
+///
+/// Combinators only poll the subfutures which were woken since the last time
+/// they were polled themselves. When several of them were woken, the first one
+/// polled wins a [`race`][crate::future::Race::race], or has its item yielded
+/// first by a [`merge`][crate::stream::Merge::merge]. `Fairness` picks which
+/// one goes first by choosing the index polling starts at. The other woken
+/// indices follow in order, wrapping around at the end.
+///
+/// Use it with [`race_with`][crate::future::Race::race_with],
+/// [`race_ok_with`][crate::future::RaceOk::race_ok_with] and
+/// [`merge_with`][crate::stream::Merge::merge_with].
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::future::Fairness;
+/// use futures_concurrency::prelude::*;
+/// use futures_lite::future::block_on;
+/// use std::future;
+///
+/// block_on(async {
+///     let futures = [future::ready("a"), future::ready("b"), future::ready("c")];
+///     let winner = futures.race_with(Fairness::Random(42)).await;
+///     assert!(["a", "b", "c"].contains(&winner));
+/// })
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Fairness {
+    /// Always start polling at the lowest index.
+    ///
+    /// When several subfutures are ready at once, the one with the lowest
+    /// index wins. This is the cheapest strategy and is what
+    /// [`race`][crate::future::Race::race] and
+    /// [`merge`][crate::stream::Merge::merge] use.
+    #[default]
+    Biased,
+    /// Advance the starting index by one on every poll, wrapping around.
+    ///
+    /// Over many polls every index gets to go first equally often, which
+    /// suits long-lived combinators such as a merged stream. The first poll
+    /// starts at index 0, so a race which completes on its first poll
+    /// behaves like [`Biased`][Fairness::Biased].
+    RoundRobin,
+    /// Pick the starting index on every poll with a pseudo-random number
+    /// generator initialized from the given seed.
+    ///
+    /// Every index is equally likely to go first, including on the first
+    /// poll. The same seed always produces the same sequence, so runs stay
+    /// reproducible. The generator is not suitable for cryptographic use.
+    Random(u64),
+}
+
+/// The per-combinator state needed to apply a [`Fairness`] strategy.
+#[derive(Debug)]
+pub(crate) struct FairnessState {
+    fairness: Fairness,
+    /// The poll counter for `RoundRobin`, or the generator state for `Random`.
+    state: u64,
+}
+
+impl FairnessState {
+    pub(crate) fn new(fairness: Fairness) -> Self {
+        let state = match fairness {
+            Fairness::Random(seed) => seed,
+            Fairness::Biased | Fairness::RoundRobin => 0,
+        };
+        Self { fairness, state }
+    }
+
+    /// Iterate over the woken indices in the order they should be polled.
+    ///
+    /// The wakers hand out `awake_list` in the order the indices were woken,
+    /// so it is sorted in place first. `len` is the total number of
+    /// subfutures.
+    pub(crate) fn order<'a>(
+        &mut self,
+        awake_list: &'a mut [usize],
+        len: usize,
+    ) -> impl Iterator<Item = &'a usize> + 'a {
+        awake_list.sort_unstable();
+        let start = match self.fairness {
+            _ if len == 0 => 0,
+            Fairness::Biased => 0,
+            Fairness::RoundRobin => {
+                let start = self.state % len as u64;
+                self.state = self.state.wrapping_add(1);
+                start as usize
+            }
+            Fairness::Random(_) => (self.next_random() % len as u64) as usize,
+        };
+        let split = awake_list.partition_point(|&idx| idx < start);
+        let (before, after) = awake_list.split_at(split);
+        after.iter().chain(before)
+    }
+
+    /// The splitmix64 generator.
+    fn next_random(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl Default for FairnessState {
+    fn default() -> Self {
+        Self::new(Fairness::Biased)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::future::Race;
+    use crate::utils::channel::local_channel;
+    use futures_lite::future::{block_on, poll_once};
+    use futures_lite::StreamExt;
+
+    fn order(state: &mut FairnessState, awake_list: &[usize], len: usize) -> Vec<usize> {
+        let mut awake_list = awake_list.to_vec();
+        state.order(&mut awake_list, len).copied().collect()
+    }
+
+    #[test]
+    fn biased() {
+        let mut state = FairnessState::new(Fairness::Biased);
+        assert_eq!(order(&mut state, &[0, 1, 2], 3), [0, 1, 2]);
+        assert_eq!(order(&mut state, &[0, 1, 2], 3), [0, 1, 2]);
+    }
+
+    #[test]
+    fn round_robin() {
+        let mut state = FairnessState::new(Fairness::RoundRobin);
+        assert_eq!(order(&mut state, &[0, 1, 2], 3), [0, 1, 2]);
+        assert_eq!(order(&mut state, &[0, 1, 2], 3), [1, 2, 0]);
+        assert_eq!(order(&mut state, &[0, 2], 3), [2, 0]);
+        assert_eq!(order(&mut state, &[0, 1, 2], 3), [0, 1, 2]);
+    }
+
+    #[test]
+    fn unsorted() {
+        let mut state = FairnessState::new(Fairness::Biased);
+        assert_eq!(order(&mut state, &[2, 0, 1], 3), [0, 1, 2]);
+
+        let mut state = FairnessState::new(Fairness::RoundRobin);
+        assert_eq!(order(&mut state, &[2, 0], 3), [0, 2]);
+        assert_eq!(order(&mut state, &[2, 0], 3), [2, 0]);
+        assert_eq!(order(&mut state, &[1, 0, 3], 4), [3, 0, 1]);
+    }
+
+    /// Children woken out of index order still go by the strategy, not by
+    /// the order they were woken in.
+    fn race_woken_out_of_order(fairness: Fairness) -> usize {
+        block_on(async {
+            let (send_a, mut a) = local_channel();
+            let (_send_b, mut b) = local_channel();
+            let (send_c, mut c) = local_channel();
+            let mut race = [a.next(), b.next(), c.next()].race_with(fairness);
+            assert!(poll_once(&mut race).await.is_none());
+
+            send_c.send(2);
+            send_a.send(0);
+            race.await.unwrap()
+        })
+    }
+
+    #[test]
+    fn race_biased_out_of_order() {
+        assert_eq!(race_woken_out_of_order(Fairness::Biased), 0);
+    }
+
+    #[test]
+    fn race_round_robin_out_of_order() {
+        // The second poll starts at index 1, so index 2 goes first.
+        assert_eq!(race_woken_out_of_order(Fairness::RoundRobin), 2);
+    }
+
+    #[test]
+    fn random_is_reproducible() {
+        let mut a = FairnessState::new(Fairness::Random(7));
+        let mut b = FairnessState::new(Fairness::Random(7));
+        let mut firsts = [0; 4];
+        for _ in 0..100 {
+            let order = order(&mut a, &[0, 1, 2, 3], 4);
+            assert_eq!(order, self::order(&mut b, &[0, 1, 2, 3], 4));
+            firsts[order[0]] += 1;
+        }
+        assert!(firsts.iter().all(|&n| n > 0));
+    }
+
+    #[test]
+    fn empty() {
+        let mut state = FairnessState::new(Fairness::RoundRobin);
+        assert_eq!(order(&mut state, &[], 0), []);
+    }
+}
@@ -0,0 +1,326 @@
+//! A growable group of futures which act as a single unit.
+
+use crate::utils::WakerVec;
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use bitvec::vec::BitVec;
+use futures_core::Stream;
+
+/// A growable group of futures which act as a single unit.
+///
+/// Futures can be inserted into and removed from the group while it is being
+/// polled. The group yields the output of each future as soon as it
+/// completes, and the future is then removed from the group. Only futures
+/// which have been woken are polled again.
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::future::FutureGroup;
+/// use futures_lite::StreamExt;
+/// use std::future;
+///
+/// futures_lite::future::block_on(async {
+///     let mut group = FutureGroup::new();
+///     group.insert(future::ready(2));
+///     group.insert(future::ready(4));
+///
+///     let mut out = 0;
+///     while let Some(num) = group.next().await {
+///         out += num;
+///     }
+///     assert_eq!(out, 6);
+/// })
+/// ```
+#[must_use = "`FutureGroup` does nothing if not iterated over"]
+pub struct FutureGroup<F> {
+    /// The futures in the group. `None` marks a vacant slot.
+    futures: Vec<Option<Pin<Box<F>>>>,
+    /// Indices of the vacant slots in `futures`, ready to be reused.
+    vacant: Vec<usize>,
+    /// Number of occupied slots in `futures`.
+    len: usize,
+    wakers: WakerVec,
+    /// Whether each slot is currently in `awake_list`.
+    awake_set: BitVec,
+    /// List of woken slots that still need to be polled.
+    awake_list: VecDeque<usize>,
+}
+
+/// A key used to index into the [`FutureGroup`] type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key(usize);
+
+impl<F> FutureGroup<F> {
+    /// Create a new, empty instance of `FutureGroup`.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create a new instance of `FutureGroup` with a given capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            futures: Vec::with_capacity(capacity),
+            vacant: Vec::new(),
+            len: 0,
+            wakers: WakerVec::new(capacity),
+            awake_set: BitVec::repeat(false, capacity),
+            awake_list: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of futures in the group.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the group contains no futures.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the group contains a future for the given key.
+    pub fn contains_key(&self, key: Key) -> bool {
+        matches!(self.futures.get(key.0), Some(Some(_)))
+    }
+
+    /// Insert a new future into the group.
+    ///
+    /// The returned key can be used to remove the future again. Keys of
+    /// futures which have completed or were removed may be handed out again.
+    pub fn insert(&mut self, future: F) -> Key
+    where
+        F: Future,
+    {
+        let index = match self.vacant.pop() {
+            Some(index) => index,
+            None => {
+                self.futures.push(None);
+                self.futures.len() - 1
+            }
+        };
+        if index >= self.wakers.len() {
+            // Grow geometrically so we don't rebuild the wakers on every insert.
+            let len = (self.wakers.len() * 2).max(index + 1);
+            self.wakers.grow(len);
+            self.awake_set.resize(len, false);
+        }
+        self.futures[index] = Some(Box::pin(future));
+        self.len += 1;
+
+        // Make sure the new future gets polled.
+        self.wakers.get(index).unwrap().wake_by_ref();
+        Key(index)
+    }
+
+    /// Remove a future from the group.
+    ///
+    /// Returns `true` if the group contained a future for the given key.
+    pub fn remove(&mut self, key: Key) -> bool {
+        match self.futures.get_mut(key.0).and_then(Option::take) {
+            Some(_) => {
+                self.vacant.push(key.0);
+                self.len -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<F> Default for FutureGroup<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F> fmt::Debug for FutureGroup<F>
+where
+    F: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.futures.iter().flatten())
+            .finish()
+    }
+}
+
+impl<F> Stream for FutureGroup<F>
+where
+    F: Future,
+{
+    type Item = F::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        {
+            let mut awakeness = this.wakers.awakeness();
+            awakeness.set_parent_waker(cx.waker());
+            let awake_set = &mut this.awake_set;
+            this.awake_list.extend(
+                awakeness
+                    .awake_list()
+                    .iter()
+                    .filter_map(|&idx| (!awake_set.replace(idx, true)).then_some(idx)),
+            );
+            awakeness.clear();
+        }
+
+        while let Some(idx) = this.awake_list.pop_front() {
+            this.awake_set.set(idx, false);
+            let fut = match this.futures.get_mut(idx) {
+                Some(Some(fut)) => fut,
+                // The slot is vacant, or was never filled.
+                _ => continue,
+            };
+            let mut cx = Context::from_waker(this.wakers.get(idx).unwrap());
+            if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+                this.futures[idx] = None;
+                this.vacant.push(idx);
+                this.len -= 1;
+                return Poll::Ready(Some(output));
+            }
+        }
+
+        if this.len == 0 {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::channel::local_channel;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use std::future;
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let mut group = FutureGroup::new();
+            group.insert(future::ready(2));
+            group.insert(future::ready(4));
+
+            let mut out = 0;
+            while let Some(num) = group.next().await {
+                out += num;
+            }
+            assert_eq!(out, 6);
+            assert!(group.is_empty());
+        });
+    }
+
+    #[test]
+    fn insert_while_iterating() {
+        block_on(async {
+            let mut group = FutureGroup::new();
+            group.insert(future::ready(1));
+
+            let mut out = vec![];
+            while let Some(num) = group.next().await {
+                if num < 5 {
+                    group.insert(future::ready(num + 1));
+                    group.insert(future::ready(num + 1));
+                }
+                out.push(num);
+            }
+            assert_eq!(out.len(), 31);
+            assert_eq!(out.iter().filter(|&&n| n == 5).count(), 16);
+        });
+    }
+
+    #[test]
+    fn remove() {
+        block_on(async {
+            let mut group = FutureGroup::new();
+            let a = group.insert(future::ready("a"));
+            let b = group.insert(future::ready("b"));
+            assert_eq!(group.len(), 2);
+
+            assert!(group.remove(a));
+            assert!(!group.remove(a));
+            assert!(!group.contains_key(a));
+            assert!(group.contains_key(b));
+
+            assert_eq!(group.next().await, Some("b"));
+            assert_eq!(group.next().await, None);
+        });
+    }
+
+    /// This test case uses channels so we'll have futures that return Pending from time to time.
+    ///
+    /// The purpose of this test is to make sure the waking logic keeps working
+    /// across inserts which grow the group.
+    #[test]
+    fn wakeups_across_growth() {
+        block_on(async {
+            let mut group = FutureGroup::new();
+            let mut senders = vec![];
+            for i in 0..10 {
+                let (s, mut r) = local_channel::<usize>();
+                senders.push(s);
+                group.insert(async move { r.next().await.unwrap() + i });
+            }
+
+            let wake_all = async move {
+                for sender in senders {
+                    sender.send(0);
+                }
+            };
+            let collect = async {
+                let mut sum = 0;
+                while let Some(n) = group.next().await {
+                    sum += n;
+                }
+                sum
+            };
+            let (sum, ()) = crate::future::Join::join((collect, wake_all)).await;
+            assert_eq!(sum, 45);
+        });
+    }
+
+    /// Inserting past the capacity rebuilds the wakers, which must not swallow
+    /// the wakeup for the new future while the group is parked.
+    #[test]
+    fn insert_past_capacity_while_parked() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use std::task::{Wake, Waker};
+
+        struct CountingWaker(AtomicUsize);
+
+        impl Wake for CountingWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = Waker::from(counter.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        let mut group = FutureGroup::with_capacity(1);
+        group.insert(future::pending().boxed());
+        assert!(Pin::new(&mut group).poll_next(&mut cx).is_pending());
+
+        let woken = counter.0.load(Ordering::SeqCst);
+        group.insert(future::ready(1).boxed());
+        assert!(counter.0.load(Ordering::SeqCst) > woken);
+        assert_eq!(
+            Pin::new(&mut group).poll_next(&mut cx),
+            Poll::Ready(Some(1))
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use super::super::common::{CombinatorArray, CombinatorBehaviorArray};
+use super::super::common::{CombinatorArray, CombinatorArrayStream, CombinatorBehaviorArray};
 use super::{Join as JoinTrait, JoinBehavior};
 
 use core::future::{Future, IntoFuture};
@@ -12,6 +12,52 @@ use core::future::{Future, IntoFuture};
 /// [`Join`]: crate::future::Join
 pub type Join<Fut, const N: usize> = CombinatorArray<Fut, JoinBehavior, N>;
 
+/// A stream which yields the output of each future as soon as it completes.
+///
+/// This `struct` is created by the `into_stream` method on [`Join`]. See its
+/// documentation for more.
+pub type JoinStream<Fut, const N: usize> = CombinatorArrayStream<Fut, JoinBehavior, N>;
+
+impl<Fut, const N: usize> Join<Fut, N>
+where
+    Fut: Future,
+{
+    /// Convert this future into a stream which yields the output of each
+    /// subfuture as soon as it completes, along with the subfuture's index.
+    ///
+    /// Unlike awaiting the combinator, the stream doesn't return early: it
+    /// yields the output of every subfuture, and ends once all of them have
+    /// completed. Drop the stream to cancel the remaining subfutures.
+    ///
+    /// To also get the output the combinator would have resolved to, call
+    /// [`keep_outputs`][JoinStream::keep_outputs] on the stream and
+    /// [`finish`][JoinStream::finish] it once it's exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    /// use futures_lite::StreamExt;
+    /// use std::future;
+    /// use std::pin::pin;
+    ///
+    /// block_on(async {
+    ///     let mut s = pin!([future::ready(1), future::ready(2)].join().into_stream().keep_outputs());
+    ///
+    ///     let mut seen = vec![];
+    ///     while let Some((index, output)) = s.next().await {
+    ///         seen.push((index, output));
+    ///     }
+    ///     assert_eq!(seen, vec![(0, 1), (1, 2)]);
+    ///     assert_eq!(s.finish(), Some([1, 2]));
+    /// })
+    /// ```
+    pub fn into_stream(self) -> JoinStream<Fut, N> {
+        CombinatorArrayStream::new(self)
+    }
+}
+
 impl<Fut, const N: usize> CombinatorBehaviorArray<Fut, N> for JoinBehavior
 where
     Fut: Future,
@@ -30,6 +76,8 @@ where
     fn when_completed_arr(arr: [Self::StoredItem; N]) -> Self::Output {
         arr
     }
+
+    const AWAITS_ALL: bool = true;
 }
 
 impl<Fut, const N: usize> JoinTrait for [Fut; N]
@@ -97,4 +145,63 @@ mod test {
             ['a', 'b', 'c', 'd', 'a', 'b', 'c', 'a', 'b', 'b']
         );
     }
+
+    #[test]
+    fn into_stream() {
+        use futures_lite::StreamExt;
+
+        futures_lite::future::block_on(async {
+            async fn yield_times(n: usize) -> usize {
+                for _ in 0..n {
+                    yield_now().await;
+                }
+                n
+            }
+            let mut s = core::pin::pin!([yield_times(2), yield_times(0), yield_times(1)]
+                .join()
+                .into_stream());
+
+            let mut order = vec![];
+            while let Some((index, output)) = s.next().await {
+                assert_eq!(output, [2, 0, 1][index]);
+                order.push(index);
+            }
+            // The future which is ready immediately completes first.
+            assert_eq!(order[0], 1);
+            order.sort_unstable();
+            assert_eq!(order, [0, 1, 2]);
+        });
+    }
+
+    #[test]
+    fn into_stream_finish() {
+        use futures_lite::StreamExt;
+
+        futures_lite::future::block_on(async {
+            let mut s = core::pin::pin!([
+                std::future::ready(1),
+                std::future::ready(2),
+                std::future::ready(3),
+            ]
+            .join()
+            .into_stream()
+            .keep_outputs());
+
+            assert_eq!(s.as_mut().finish(), None);
+            while s.next().await.is_some() {}
+            assert_eq!(s.as_mut().finish(), Some([1, 2, 3]));
+            assert_eq!(s.as_mut().finish(), None);
+        });
+    }
+
+    #[test]
+    fn into_stream_finish_without_keeping() {
+        use futures_lite::StreamExt;
+
+        futures_lite::future::block_on(async {
+            let mut s = core::pin::pin!([std::future::ready(1)].join().into_stream());
+            while s.next().await.is_some() {}
+            assert_eq!(s.finish(), None);
+        });
+    }
 }
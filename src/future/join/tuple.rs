@@ -27,6 +27,8 @@ impl<R, S> MapResult<Result<R, S>> for MapResultJoin {
             Ok(r) => r,
         }
     }
+
+    const AWAITS_ALL: bool = true;
 }
 
 macro_rules! impl_join_tuple {
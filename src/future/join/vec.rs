@@ -1,8 +1,8 @@
-use super::super::common::{CombinatorBehaviorVec, CombinatorVec};
+use super::super::common::{CombinatorBehaviorVec, CombinatorVec, CombinatorVecStream};
 use super::{Join as JoinTrait, JoinBehavior};
 
+use alloc::vec::Vec;
 use core::future::{Future, IntoFuture};
-use std::vec::Vec;
 
 /// Waits for two similarly-typed futures to complete.
 ///
@@ -13,6 +13,52 @@ use std::vec::Vec;
 /// [`Join`]: crate::future::Join
 pub type Join<Fut> = CombinatorVec<Fut, JoinBehavior>;
 
+/// A stream which yields the output of each future as soon as it completes.
+///
+/// This `struct` is created by the `into_stream` method on [`Join`]. See its
+/// documentation for more.
+pub type JoinStream<Fut> = CombinatorVecStream<Fut, JoinBehavior>;
+
+impl<Fut> Join<Fut>
+where
+    Fut: Future,
+{
+    /// Convert this future into a stream which yields the output of each
+    /// subfuture as soon as it completes, along with the subfuture's index.
+    ///
+    /// Unlike awaiting the combinator, the stream doesn't return early: it
+    /// yields the output of every subfuture, and ends once all of them have
+    /// completed. Drop the stream to cancel the remaining subfutures.
+    ///
+    /// To also get the output the combinator would have resolved to, call
+    /// [`keep_outputs`][JoinStream::keep_outputs] on the stream and
+    /// [`finish`][JoinStream::finish] it once it's exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    /// use futures_lite::StreamExt;
+    /// use std::future;
+    /// use std::pin::pin;
+    ///
+    /// block_on(async {
+    ///     let mut s = pin!(vec![future::ready(1), future::ready(2)].join().into_stream().keep_outputs());
+    ///
+    ///     let mut seen = vec![];
+    ///     while let Some((index, output)) = s.next().await {
+    ///         seen.push((index, output));
+    ///     }
+    ///     assert_eq!(seen, vec![(0, 1), (1, 2)]);
+    ///     assert_eq!(s.finish(), Some(vec![1, 2]));
+    /// })
+    /// ```
+    pub fn into_stream(self) -> JoinStream<Fut> {
+        CombinatorVecStream::new(self)
+    }
+}
+
 impl<Fut> CombinatorBehaviorVec<Fut> for JoinBehavior
 where
     Fut: Future,
@@ -58,4 +104,47 @@ mod test {
             assert_eq!(fut.await, vec!["hello", "world"]);
         });
     }
+
+    #[test]
+    fn into_stream() {
+        use futures_lite::future::yield_now;
+        use futures_lite::StreamExt;
+
+        futures_lite::future::block_on(async {
+            async fn yield_times(n: usize) -> usize {
+                for _ in 0..n {
+                    yield_now().await;
+                }
+                n
+            }
+            let mut s = core::pin::pin!(vec![yield_times(2), yield_times(0), yield_times(1)]
+                .join()
+                .into_stream());
+
+            let mut order = vec![];
+            while let Some((index, output)) = s.next().await {
+                assert_eq!(output, [2, 0, 1][index]);
+                order.push(index);
+            }
+            // The future which is ready immediately completes first.
+            assert_eq!(order[0], 1);
+            order.sort_unstable();
+            assert_eq!(order, [0, 1, 2]);
+        });
+    }
+
+    #[test]
+    fn into_stream_finish() {
+        use futures_lite::StreamExt;
+
+        futures_lite::future::block_on(async {
+            let mut s = core::pin::pin!(vec![std::future::ready(1), std::future::ready(2)]
+                .join()
+                .into_stream()
+                .keep_outputs());
+
+            while s.next().await.is_some() {}
+            assert_eq!(s.finish(), Some(vec![1, 2]));
+        });
+    }
 }
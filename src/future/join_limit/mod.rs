@@ -0,0 +1,41 @@
+use core::future::Future;
+
+pub(crate) mod vec;
+
+/// Wait for all futures to complete, with a limit on how many futures are
+/// polled at once.
+///
+/// This is implemented for any iterator of futures, including vectors.
+pub trait JoinLimit {
+    /// The resulting output type.
+    type Output;
+
+    /// Which kind of future are we turning this into?
+    type Future: Future<Output = Self::Output>;
+
+    /// Waits for multiple futures to complete, keeping at most `limit`
+    /// futures in flight.
+    ///
+    /// Futures are started in iteration order. Once a future completes, the
+    /// next future is started in its place. The outputs are returned in
+    /// iteration order, once all futures complete.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::future::JoinLimit;
+    /// use futures_lite::future::block_on;
+    /// use std::future;
+    ///
+    /// block_on(async {
+    ///     let futs = (0..100).map(future::ready);
+    ///     let outputs = futs.join_limit(10).await;
+    ///     assert_eq!(outputs, (0..100).collect::<Vec<_>>());
+    /// })
+    /// ```
+    fn join_limit(self, limit: usize) -> Self::Future;
+}
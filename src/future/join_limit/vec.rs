@@ -0,0 +1,95 @@
+use super::super::common::CombinatorLimit;
+use super::super::join::JoinBehavior;
+use super::JoinLimit as JoinLimitTrait;
+
+use alloc::vec::Vec;
+use core::future::IntoFuture;
+
+/// Wait for all futures to complete, with a limit on how many futures are
+/// polled at once.
+///
+/// This `struct` is created by the [`join_limit`] method on the [`JoinLimit`]
+/// trait. See its documentation for more.
+///
+/// [`join_limit`]: crate::future::JoinLimit::join_limit
+/// [`JoinLimit`]: crate::future::JoinLimit
+pub type JoinLimit<I> = CombinatorLimit<I, JoinBehavior>;
+
+impl<I> JoinLimitTrait for I
+where
+    I: IntoIterator,
+    I::Item: IntoFuture,
+{
+    type Output = Vec<<I::Item as IntoFuture>::Output>;
+    type Future = JoinLimit<I::IntoIter>;
+
+    fn join_limit(self, limit: usize) -> Self::Future {
+        JoinLimit::new(self.into_iter(), limit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::channel::local_channel;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use std::cell::Cell;
+    use std::future;
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let res = vec![future::ready("hello"), future::ready("world")]
+                .join_limit(1)
+                .await;
+            assert_eq!(res, vec!["hello", "world"]);
+        });
+    }
+
+    #[test]
+    fn empty() {
+        block_on(async {
+            let data: Vec<future::Ready<()>> = vec![];
+            assert_eq!(data.join_limit(3).await, vec![]);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "greater than zero")]
+    fn zero_limit() {
+        drop(vec![future::ready(())].join_limit(0));
+    }
+
+    /// Out-of-order completion must still return outputs in order, while
+    /// never running more than `limit` futures at once.
+    #[test]
+    fn limits_in_flight() {
+        block_on(async {
+            let in_flight = Cell::new(0);
+            let max_in_flight = Cell::new(0);
+            let (senders, receivers): (Vec<_>, Vec<_>) =
+                (0..6).map(|_| local_channel::<usize>()).unzip();
+
+            let futs = receivers.into_iter().map(|mut receiver| {
+                let in_flight = &in_flight;
+                let max_in_flight = &max_in_flight;
+                async move {
+                    in_flight.set(in_flight.get() + 1);
+                    max_in_flight.set(max_in_flight.get().max(in_flight.get()));
+                    let n = receiver.next().await.unwrap();
+                    in_flight.set(in_flight.get() - 1);
+                    n
+                }
+            });
+            let send_all = async {
+                for (i, sender) in senders.iter().enumerate().rev() {
+                    sender.send(i);
+                }
+            };
+            let (res, ()) = crate::future::Join::join((futs.join_limit(2), send_all)).await;
+            assert_eq!(res, vec![0, 1, 2, 3, 4, 5]);
+            assert_eq!(max_in_flight.get(), 2);
+        });
+    }
+}
@@ -46,7 +46,7 @@
 //! When working with futures which don't return `Result` types, we
 //! provide two built-in concurrency operations:
 //!
-//! - `future::Merge`: wait for all futures in the set to complete
+//! - `future::Join`: wait for all futures in the set to complete
 //! - `future::Race`: wait for the _first_ future in the set to complete
 //!
 //! Because futures can be considered to be an async sequence of one, see
@@ -61,21 +61,76 @@
 //!
 //! |                             | __Wait for all outputs__ | __Wait for first output__ |
 //! | ---                         | ---                      | ---                       |
-//! | __Continue on error__       | `future::Merge`          | `future::RaceOk`
-//! | __Return early on error__   | `future::TryMerge`       | `future::Race`
+//! | __Continue on error__       | `future::Settle`         | `future::RaceOk`
+//! | __Return early on error__   | `future::TryJoin`        | `future::Race`
 //!
-//! - `future::TryMerge`: wait for all futures in the set to complete _successfully_, or return on the first error.
+//! - `future::Settle`: wait for all futures in the set to complete, and keep
+//!   the result of every future.
+//! - `future::TryJoin`: wait for all futures in the set to complete _successfully_, or return on the first error.
 //! - `future::RaceOk`: wait for the first _successful_ future in the set to
-//! complete, or return an `Err` if *no* futures complete successfully.
+//!   complete, or return an `Err` if *no* futures complete successfully.
+//!
+//! ## Timeouts
+//!
+//! Arrays and vectors of futures can also be awaited until a deadline elapses,
+//! using `future::JoinTimeout`, `future::TryJoinTimeout`, and
+//! `future::RaceTimeout`. A deadline is any future which outputs `()`; the
+//! `future::Timer` trait lets a runtime's timer create deadlines from a
+//! duration, which is what the `*_timeout_with` methods take. When the
+//! deadline elapses first, a `future::TimedOut` error is returned, holding the
+//! outputs of the futures which did complete.
+//!
+//! ## Task Groups
+//!
+//! `future::FutureGroup` awaits a set of futures which can grow while it is
+//! being polled. `future::TaskGroup` builds on it to provide a scope for
+//! fallible tasks: tasks can spawn more tasks into the group through a
+//! `task_group::Spawner`, the group completes once all tasks have completed,
+//! and the first error cancels the remaining tasks. `future::LocalTaskGroup`
+//! does the same for tasks which aren't `Send`.
+//!
+//! ## Fairness
+//!
+//! When several futures are ready at the same time, `race` and `race_ok`
+//! return the output of the one with the lowest index. `race_with` and
+//! `race_ok_with` take a `future::Fairness` strategy instead, which can rotate
+//! or randomize the index polling starts at. The same strategies are available
+//! for merging streams through `merge_with`.
 //!
 pub use common::select_types;
+pub use fairness::Fairness;
+#[cfg(feature = "alloc")]
+pub use future_group::FutureGroup;
 pub use join::Join;
+#[cfg(feature = "alloc")]
+pub use join_limit::JoinLimit;
 pub use race::Race;
 pub use race_ok::RaceOk;
+pub use race_ok_staggered::RaceOkStaggered;
+pub use settle::{Settle, Settled};
+#[cfg(feature = "std")]
+pub use task_group::{LocalTaskGroup, TaskGroup};
+pub use timeout::{
+    JoinTimeout, PartialFuture, RaceTimeout, TimedOut, Timeout, Timer, TryJoinTimeout,
+};
 pub use try_join::TryJoin;
+#[cfg(feature = "alloc")]
+pub use try_join_limit::TryJoinLimit;
 
 mod common;
+pub(crate) mod fairness;
+#[cfg(feature = "alloc")]
+pub mod future_group;
 pub(crate) mod join;
+#[cfg(feature = "alloc")]
+pub(crate) mod join_limit;
 pub(crate) mod race;
 pub(crate) mod race_ok;
+pub(crate) mod race_ok_staggered;
+pub(crate) mod settle;
+#[cfg(feature = "std")]
+pub mod task_group;
+pub(crate) mod timeout;
 pub(crate) mod try_join;
+#[cfg(feature = "alloc")]
+pub(crate) mod try_join_limit;
@@ -1,4 +1,5 @@
 use super::super::common::{CombinatorArray, CombinatorBehaviorArray};
+use super::super::Fairness;
 use super::{Race as RaceTrait, RaceBehavior};
 
 use core::future::{Future, IntoFuture};
@@ -42,6 +43,10 @@ where
     fn race(self) -> Self::Future {
         Race::new(self.map(IntoFuture::into_future))
     }
+
+    fn race_with(self, fairness: Fairness) -> Self::Future {
+        Race::new(self.map(IntoFuture::into_future)).with_fairness(fairness)
+    }
 }
 
 #[cfg(test)]
@@ -59,4 +64,28 @@ mod test {
             assert!(matches!(res, "hello" | "world"));
         });
     }
+
+    #[test]
+    fn random_fairness() {
+        // Every future is ready on the first poll, so the seed picks the winner.
+        let winners: std::collections::HashSet<_> = (0..32)
+            .map(|seed| {
+                let futures = [future::ready(0), future::ready(1), future::ready(2)];
+                futures_lite::future::block_on(futures.race_with(Fairness::Random(seed)))
+            })
+            .collect();
+        assert_eq!(winners.len(), 3);
+    }
+
+    #[test]
+    fn round_robin_fairness() {
+        futures_lite::future::block_on(async {
+            // All futures become ready on the second poll, which starts at index 1.
+            let futures = [0, 1, 2].map(|i| async move {
+                futures_lite::future::yield_now().await;
+                i
+            });
+            assert_eq!(futures.race_with(Fairness::RoundRobin).await, 1);
+        });
+    }
 }
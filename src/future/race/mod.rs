@@ -1,7 +1,10 @@
+use super::Fairness;
+
 use core::future::Future;
 
 pub(crate) mod array;
 pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
 pub(crate) mod vec;
 
 /// Wait for the first future to complete.
@@ -22,6 +25,38 @@ pub trait Race {
     ///
     /// This function returns a new future which polls all futures concurrently.
     fn race(self) -> Self::Future;
+
+    /// Wait for the first future to complete, breaking ties between futures
+    /// which are ready at the same time according to `fairness`.
+    ///
+    /// [`race`][Race::race] always favors the future with the lowest index.
+    /// See [`Fairness`] for the other strategies.
+    ///
+    /// The default implementation ignores `fairness` and calls
+    /// [`race`][Race::race]. The implementations in this crate override it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::future::Fairness;
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    /// use std::future;
+    ///
+    /// block_on(async {
+    ///     let a = future::ready("a");
+    ///     let b = future::ready("b");
+    ///     let winner = [a, b].race_with(Fairness::Random(7)).await;
+    ///     assert!(matches!(winner, "a" | "b"));
+    /// })
+    /// ```
+    fn race_with(self, fairness: Fairness) -> Self::Future
+    where
+        Self: Sized,
+    {
+        let _ = fairness;
+        self.race()
+    }
 }
 
 #[derive(Debug)]
@@ -1,4 +1,5 @@
 use super::super::common::{CombineTuple, MapResult};
+use super::super::Fairness;
 use super::Race as RaceTrait;
 
 use core::fmt::Debug;
@@ -46,6 +47,10 @@ macro_rules! impl_race_tuple {
                     MapResultRace
                 ).combine()
             }
+
+            fn race_with(self, fairness: Fairness) -> Self::Future {
+                self.race().with_fairness(fairness)
+            }
         }
     };
 }
@@ -95,4 +100,28 @@ mod test {
             assert!(matches!(result, "hello" | "world"));
         });
     }
+
+    #[test]
+    fn random_fairness() {
+        let winners: std::collections::HashSet<_> = (0..32)
+            .map(|seed| {
+                let futures = (future::ready(0), future::ready(1), future::ready(2));
+                futures_lite::future::block_on(futures.race_with(Fairness::Random(seed))).any()
+            })
+            .collect();
+        assert_eq!(winners.len(), 3);
+    }
+
+    #[test]
+    fn terminated_after_early_return() {
+        use futures_core::future::FusedFuture;
+        use std::pin::pin;
+
+        futures_lite::future::block_on(async {
+            let mut fut = pin!((future::ready(1), future::pending::<u8>()).race());
+            assert!(!fut.is_terminated());
+            assert_eq!(fut.as_mut().await.any(), 1);
+            assert!(fut.is_terminated());
+        });
+    }
 }
@@ -1,6 +1,8 @@
 use super::super::common::{CombinatorBehaviorVec, CombinatorVec};
+use super::super::Fairness;
 use super::{Race as RaceTrait, RaceBehavior};
 
+use alloc::vec::Vec;
 use core::future::{Future, IntoFuture};
 
 /// Wait for the first future to complete.
@@ -42,6 +44,10 @@ where
     fn race(self) -> Self::Future {
         Race::new(self.into_iter().map(IntoFuture::into_future).collect())
     }
+
+    fn race_with(self, fairness: Fairness) -> Self::Future {
+        Race::new(self.into_iter().map(IntoFuture::into_future).collect()).with_fairness(fairness)
+    }
 }
 
 #[cfg(test)]
@@ -59,4 +65,14 @@ mod test {
             assert!(matches!(res, "hello" | "world"));
         });
     }
+
+    #[test]
+    fn biased_fairness() {
+        futures_lite::future::block_on(async {
+            let res = vec![future::ready("hello"), future::ready("world")]
+                .race_with(Fairness::Biased)
+                .await;
+            assert_eq!(res, "hello");
+        });
+    }
 }
@@ -1,4 +1,5 @@
 use super::super::common::{CombinatorArray, CombinatorBehaviorArray};
+use super::super::Fairness;
 use super::{RaceOk as RaceOkTrait, RaceOkBehavior};
 
 use core::future::{Future, IntoFuture};
@@ -47,13 +48,17 @@ where
     fn race_ok(self) -> Self::Future {
         RaceOk::new(self.map(IntoFuture::into_future))
     }
+
+    fn race_ok_with(self, fairness: Fairness) -> Self::Future {
+        RaceOk::new(self.map(IntoFuture::into_future)).with_fairness(fairness)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use std::future;
-    use std::io::{Error, ErrorKind};
+    use std::io::Error;
 
     #[test]
     fn all_ok() {
@@ -69,7 +74,7 @@ mod test {
     #[test]
     fn one_err() {
         futures_lite::future::block_on(async {
-            let err = Error::new(ErrorKind::Other, "oh no");
+            let err = Error::other("oh no");
             let res: Result<&str, [Error; 2]> =
                 [future::ready(Ok("hello")), future::ready(Err(err))]
                     .race_ok()
@@ -81,8 +86,8 @@ mod test {
     #[test]
     fn all_err() {
         futures_lite::future::block_on(async {
-            let err1 = Error::new(ErrorKind::Other, "oops");
-            let err2 = Error::new(ErrorKind::Other, "oh no");
+            let err1 = Error::other("oops");
+            let err2 = Error::other("oh no");
             let res: Result<&str, [Error; 2]> =
                 [future::ready(Err(err1)), future::ready(Err(err2))]
                     .race_ok()
@@ -1,7 +1,10 @@
+use super::Fairness;
+
 use core::future::Future;
 
 pub(crate) mod array;
 pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
 pub(crate) mod vec;
 
 /// Wait for the first successful future to complete.
@@ -21,6 +24,23 @@ pub trait RaceOk {
 
     /// Waits for the first successful future to complete.
     fn race_ok(self) -> Self::Future;
+
+    /// Waits for the first successful future to complete, breaking ties
+    /// between futures which succeed at the same time according to
+    /// `fairness`.
+    ///
+    /// See [`Fairness`] for the available strategies.
+    ///
+    /// The default implementation ignores `fairness` and calls
+    /// [`race_ok`][RaceOk::race_ok]. The implementations in this crate
+    /// override it.
+    fn race_ok_with(self, fairness: Fairness) -> Self::Future
+    where
+        Self: Sized,
+    {
+        let _ = fairness;
+        self.race_ok()
+    }
 }
 
 #[derive(Debug)]
@@ -1,4 +1,5 @@
 use super::super::common::{CombineTuple, MapResult};
+use super::super::Fairness;
 use super::RaceOk as RaceOkTrait;
 
 use core::fmt::Debug;
@@ -53,6 +54,10 @@ macro_rules! impl_race_ok_tuple {
                     MapResultRaceOk
                 ).combine()
             }
+
+            fn race_ok_with(self, fairness: Fairness) -> Self::Future {
+                self.race_ok().with_fairness(fairness)
+            }
         }
     };
 }
@@ -1,8 +1,9 @@
 use super::super::common::{CombinatorBehaviorVec, CombinatorVec};
+use super::super::Fairness;
 use super::{RaceOk as RaceOkTrait, RaceOkBehavior};
 
+use alloc::vec::Vec;
 use core::future::{Future, IntoFuture};
-use std::vec::Vec;
 
 /// Wait for the first successful future to complete.
 ///
@@ -47,13 +48,17 @@ where
     fn race_ok(self) -> Self::Future {
         RaceOk::new(self.into_iter().map(IntoFuture::into_future).collect())
     }
+
+    fn race_ok_with(self, fairness: Fairness) -> Self::Future {
+        RaceOk::new(self.into_iter().map(IntoFuture::into_future).collect()).with_fairness(fairness)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use std::future;
-    use std::io::{Error, ErrorKind};
+    use std::io::Error;
 
     #[test]
     fn all_ok() {
@@ -69,7 +74,7 @@ mod test {
     #[test]
     fn one_err() {
         futures_lite::future::block_on(async {
-            let err = Error::new(ErrorKind::Other, "oh no");
+            let err = Error::other("oh no");
             let res: Result<&str, Vec<Error>> =
                 vec![future::ready(Ok("hello")), future::ready(Err(err))]
                     .race_ok()
@@ -81,8 +86,8 @@ mod test {
     #[test]
     fn all_err() {
         futures_lite::future::block_on(async {
-            let err1 = Error::new(ErrorKind::Other, "oops");
-            let err2 = Error::new(ErrorKind::Other, "oh no");
+            let err1 = Error::other("oops");
+            let err2 = Error::other("oh no");
             let res: Result<&str, Vec<Error>> =
                 vec![future::ready(Err(err1)), future::ready(Err(err2))]
                     .race_ok()
@@ -92,4 +97,16 @@ mod test {
             assert_eq!(errs[1].to_string(), "oh no");
         });
     }
+
+    #[test]
+    fn random_fairness() {
+        let winners: std::collections::HashSet<_> = (0..32)
+            .map(|seed| {
+                let futures: Vec<_> = (0..3).map(|i| future::ready(Ok::<_, Error>(i))).collect();
+                futures_lite::future::block_on(futures.race_ok_with(Fairness::Random(seed)))
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(winners.len(), 3);
+    }
 }
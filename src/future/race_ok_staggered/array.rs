@@ -0,0 +1,274 @@
+use super::super::Timer;
+use super::RaceOkStaggered as RaceOkStaggeredTrait;
+use crate::utils::{self, WakerArray};
+
+use core::array;
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::mem::MaybeUninit;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+
+use futures_core::future::{FusedFuture, TryFuture};
+use pin_project::{pin_project, pinned_drop};
+
+/// Wait for the first successful future to complete, starting the futures one
+/// after another.
+///
+/// This `struct` is created by the [`race_ok_staggered`] method on the
+/// [`RaceOkStaggered`] trait. See its documentation for more.
+///
+/// [`race_ok_staggered`]: crate::future::RaceOkStaggered::race_ok_staggered
+/// [`RaceOkStaggered`]: crate::future::RaceOkStaggered
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project(PinnedDrop)]
+pub struct RaceOkStaggered<Fut, T, const N: usize>
+where
+    Fut: TryFuture,
+    T: Timer,
+{
+    /// Number of futures which have been started.
+    started: usize,
+    /// The error of each future which has failed.
+    errors: [MaybeUninit<Fut::Error>; N],
+    /// Which futures have failed, and hold an initialized error.
+    failed: [bool; N],
+    /// Number of futures which have failed.
+    num_failed: usize,
+    wakers: WakerArray<N>,
+    awake_list_buffer: [usize; N],
+    timer: T,
+    /// How long to wait before starting the next future.
+    dur: Duration,
+    /// The delay after which the next future is started.
+    #[pin]
+    delay: Option<T::Delay>,
+    done: bool,
+    #[pin]
+    futures: [Fut; N],
+}
+
+impl<Fut, T, const N: usize> RaceOkStaggered<Fut, T, N>
+where
+    Fut: TryFuture,
+    T: Timer,
+{
+    pub(crate) fn new(futures: [Fut; N], timer: T, dur: Duration) -> Self {
+        Self {
+            started: 0,
+            errors: array::from_fn(|_| MaybeUninit::uninit()),
+            failed: [false; N],
+            num_failed: 0,
+            wakers: WakerArray::new(),
+            awake_list_buffer: [0; N],
+            timer,
+            dur,
+            delay: None,
+            done: false,
+            futures,
+        }
+    }
+}
+
+impl<Fut, T, const N: usize> fmt::Debug for RaceOkStaggered<Fut, T, N>
+where
+    Fut: TryFuture + fmt::Debug,
+    T: Timer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.futures.iter().take(self.started))
+            .finish()
+    }
+}
+
+impl<Fut, T, const N: usize> Future for RaceOkStaggered<Fut, T, N>
+where
+    Fut: TryFuture,
+    T: Timer,
+{
+    type Output = Result<Fut::Ok, [Fut::Error; N]>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        assert!(!*this.done, "Futures must not be polled after completing");
+
+        // Number of futures to start: one for every failure, and one for
+        // every elapsed delay.
+        let mut to_start = usize::from(*this.started == 0);
+
+        loop {
+            if to_start > 0 && *this.started < N {
+                // Starting a future means polling it for the first time.
+                let end = (*this.started + to_start).min(N);
+                for idx in *this.started..end {
+                    this.wakers.get(idx).unwrap().wake_by_ref();
+                }
+                *this.started = end;
+                match *this.started < N {
+                    true => this.delay.set(Some(this.timer.delay(*this.dur))),
+                    false => this.delay.set(None),
+                }
+            }
+            to_start = 0;
+
+            let num_awake = {
+                let mut awakeness = this.wakers.awakeness();
+                awakeness.set_parent_waker(cx.waker());
+                let awake_list = awakeness.awake_list();
+                let num_awake = awake_list.len();
+                this.awake_list_buffer[..num_awake].copy_from_slice(awake_list);
+                awakeness.clear();
+                num_awake
+            };
+
+            for &idx in this.awake_list_buffer.iter().take(num_awake) {
+                // Futures which haven't started yet are woken when they start,
+                // and failed futures must not be polled again.
+                if idx >= *this.started || this.failed[idx] {
+                    continue;
+                }
+                let fut = utils::get_pin_mut(this.futures.as_mut(), idx).unwrap();
+                let mut cx = Context::from_waker(this.wakers.get(idx).unwrap());
+                match fut.try_poll(&mut cx) {
+                    Poll::Ready(Ok(value)) => {
+                        *this.done = true;
+                        return Poll::Ready(Ok(value));
+                    }
+                    Poll::Ready(Err(err)) => {
+                        this.errors[idx].write(err);
+                        this.failed[idx] = true;
+                        *this.num_failed += 1;
+                        to_start += 1;
+                    }
+                    Poll::Pending => {}
+                }
+            }
+
+            if *this.num_failed == N {
+                *this.done = true;
+                this.failed.fill(false);
+
+                let mut errors = array::from_fn(|_| MaybeUninit::uninit());
+                core::mem::swap(this.errors, &mut errors);
+
+                // SAFETY: num_failed is only incremented when an error slot is
+                // filled, so every slot is filled once it reaches N. We've
+                // marked them as unfilled, so they won't be dropped again.
+                let errors = unsafe { utils::array_assume_init(errors) };
+                return Poll::Ready(Err(errors));
+            }
+
+            if let Some(delay) = this.delay.as_mut().as_pin_mut() {
+                if delay.poll(cx).is_ready() {
+                    to_start += 1;
+                }
+            }
+
+            if to_start == 0 {
+                return Poll::Pending;
+            }
+        }
+    }
+}
+
+impl<Fut, T, const N: usize> FusedFuture for RaceOkStaggered<Fut, T, N>
+where
+    Fut: TryFuture,
+    T: Timer,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+/// Drop the errors of the failed futures on cancellation.
+#[pinned_drop]
+impl<Fut, T, const N: usize> PinnedDrop for RaceOkStaggered<Fut, T, N>
+where
+    Fut: TryFuture,
+    T: Timer,
+{
+    fn drop(self: Pin<&mut Self>) {
+        let this = self.project();
+
+        for (&failed, error) in this.failed.iter().zip(this.errors.iter_mut()) {
+            if failed {
+                // SAFETY: failed is only set to true for initialized errors.
+                unsafe { error.assume_init_drop() };
+            }
+        }
+    }
+}
+
+impl<Fut, V, E, const N: usize> RaceOkStaggeredTrait for [Fut; N]
+where
+    Fut: IntoFuture<Output = Result<V, E>>,
+{
+    type Ok = V;
+    type Error = [E; N];
+    type Future<T>
+        = RaceOkStaggered<Fut::IntoFuture, T, N>
+    where
+        T: Timer;
+
+    fn race_ok_staggered<T>(self, timer: T, dur: Duration) -> Self::Future<T>
+    where
+        T: Timer,
+    {
+        RaceOkStaggered::new(self.map(IntoFuture::into_future), timer, dur)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures_lite::future::poll_once;
+    use std::future;
+    use std::pin::pin;
+    use std::rc::Rc;
+
+    fn never(_: Duration) -> future::Pending<()> {
+        future::pending()
+    }
+
+    #[test]
+    fn all_err() {
+        futures_lite::future::block_on(async {
+            let res: Result<(), _> = [future::ready(Err("oops")), future::ready(Err("oh no"))]
+                .race_ok_staggered(never, Duration::ZERO)
+                .await;
+            assert_eq!(res, Err(["oops", "oh no"]));
+        });
+    }
+
+    #[test]
+    fn one_ok() {
+        futures_lite::future::block_on(async {
+            let res = [future::ready(Err("oops")), future::ready(Ok("hello"))]
+                .race_ok_staggered(never, Duration::ZERO)
+                .await;
+            assert_eq!(res, Ok("hello"));
+        });
+    }
+
+    #[test]
+    fn drops_errors_on_cancel() {
+        futures_lite::future::block_on(async {
+            let err = Rc::new(());
+            {
+                let futs = [
+                    Box::pin(future::ready(Err(err.clone())))
+                        as Pin<Box<dyn Future<Output = Result<(), Rc<()>>>>>,
+                    Box::pin(future::pending()),
+                ];
+                let mut race = pin!(futs.race_ok_staggered(never, Duration::ZERO));
+                assert!(poll_once(race.as_mut()).await.is_none());
+                assert_eq!(Rc::strong_count(&err), 2);
+            }
+            assert_eq!(Rc::strong_count(&err), 1);
+        });
+    }
+}
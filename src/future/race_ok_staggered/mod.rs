@@ -0,0 +1,67 @@
+use super::Timer;
+
+use core::future::Future;
+use core::time::Duration;
+
+pub(crate) mod array;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Wait for the first successful future to complete, starting the futures one
+/// after another.
+///
+/// This is a staggered version of [`RaceOk`][super::RaceOk], which is
+/// useful when a request can be served by several endpoints, such as when
+/// connecting to one of many resolved addresses ("happy eyeballs"), or
+/// reading from replicas or mirrors.
+pub trait RaceOkStaggered {
+    /// The resulting output type.
+    type Ok;
+
+    /// The resulting error type.
+    type Error;
+
+    /// Which kind of future are we turning this into?
+    type Future<T>: Future<Output = Result<Self::Ok, Self::Error>>
+    where
+        T: Timer;
+
+    /// Waits for the first successful future to complete, starting the
+    /// futures one after another.
+    ///
+    /// Only the first future is started right away. The next future is
+    /// started as soon as a started future fails, or once `dur` has elapsed
+    /// on the given [`Timer`], whichever happens first. The delay is restarted
+    /// every time a future is started.
+    ///
+    /// Returns the output of the first future which completes successfully,
+    /// cancelling all others. If no future completes successfully, returns
+    /// the errors of all futures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    /// use std::future;
+    /// use std::time::Duration;
+    ///
+    /// block_on(async {
+    ///     // With a runtime this would be its sleep function, such as
+    ///     // `async_std::task::sleep`.
+    ///     let sleep = |_| future::pending();
+    ///
+    ///     let attempts = [
+    ///         future::ready(Err("unreachable")),
+    ///         future::ready(Ok("connected")),
+    ///     ];
+    ///     let res = attempts
+    ///         .race_ok_staggered(sleep, Duration::from_millis(250))
+    ///         .await;
+    ///     assert_eq!(res, Ok("connected"));
+    /// })
+    /// ```
+    fn race_ok_staggered<T>(self, timer: T, dur: Duration) -> Self::Future<T>
+    where
+        T: Timer;
+}
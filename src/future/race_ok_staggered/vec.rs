@@ -0,0 +1,320 @@
+use super::super::Timer;
+use super::RaceOkStaggered as RaceOkStaggeredTrait;
+use crate::utils::{self, WakerVec};
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+
+use futures_core::future::{FusedFuture, TryFuture};
+use pin_project::pin_project;
+
+/// Wait for the first successful future to complete, starting the futures one
+/// after another.
+///
+/// This `struct` is created by the [`race_ok_staggered`] method on the
+/// [`RaceOkStaggered`] trait. See its documentation for more.
+///
+/// [`race_ok_staggered`]: crate::future::RaceOkStaggered::race_ok_staggered
+/// [`RaceOkStaggered`]: crate::future::RaceOkStaggered
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct RaceOkStaggered<Fut, T>
+where
+    Fut: TryFuture,
+    T: Timer,
+{
+    /// Number of futures which have been started.
+    started: usize,
+    /// The error of each future which has failed.
+    errors: Vec<Option<Fut::Error>>,
+    /// Number of futures which have failed.
+    failed: usize,
+    wakers: WakerVec,
+    awake_list_buffer: Vec<usize>,
+    timer: T,
+    /// How long to wait before starting the next future.
+    dur: Duration,
+    /// The delay after which the next future is started.
+    #[pin]
+    delay: Option<T::Delay>,
+    done: bool,
+    #[pin]
+    futures: Vec<Fut>,
+}
+
+impl<Fut, T> RaceOkStaggered<Fut, T>
+where
+    Fut: TryFuture,
+    T: Timer,
+{
+    pub(crate) fn new(futures: Vec<Fut>, timer: T, dur: Duration) -> Self {
+        let len = futures.len();
+        Self {
+            started: 0,
+            errors: core::iter::repeat_with(|| None).take(len).collect(),
+            failed: 0,
+            wakers: WakerVec::new(len),
+            awake_list_buffer: Vec::new(),
+            timer,
+            dur,
+            delay: None,
+            done: false,
+            futures,
+        }
+    }
+}
+
+impl<Fut, T> fmt::Debug for RaceOkStaggered<Fut, T>
+where
+    Fut: TryFuture + fmt::Debug,
+    T: Timer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.futures.iter().take(self.started))
+            .finish()
+    }
+}
+
+impl<Fut, T> Future for RaceOkStaggered<Fut, T>
+where
+    Fut: TryFuture,
+    T: Timer,
+{
+    type Output = Result<Fut::Ok, Vec<Fut::Error>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        assert!(!*this.done, "Futures must not be polled after completing");
+
+        let len = this.futures.len();
+        // Number of futures to start: one for every failure, and one for
+        // every elapsed delay.
+        let mut to_start = usize::from(*this.started == 0);
+
+        loop {
+            if to_start > 0 && *this.started < len {
+                // Starting a future means polling it for the first time.
+                let end = (*this.started + to_start).min(len);
+                for idx in *this.started..end {
+                    this.wakers.get(idx).unwrap().wake_by_ref();
+                }
+                *this.started = end;
+                match *this.started < len {
+                    true => this.delay.set(Some(this.timer.delay(*this.dur))),
+                    false => this.delay.set(None),
+                }
+            }
+            to_start = 0;
+
+            {
+                let mut awakeness = this.wakers.awakeness();
+                awakeness.set_parent_waker(cx.waker());
+                this.awake_list_buffer.clone_from(awakeness.awake_list());
+                awakeness.clear();
+            }
+
+            for &idx in this.awake_list_buffer.iter() {
+                // Futures which haven't started yet are woken when they start,
+                // and failed futures must not be polled again.
+                if idx >= *this.started || this.errors[idx].is_some() {
+                    continue;
+                }
+                let fut = utils::get_pin_mut_from_vec(this.futures.as_mut(), idx).unwrap();
+                let mut cx = Context::from_waker(this.wakers.get(idx).unwrap());
+                match fut.try_poll(&mut cx) {
+                    Poll::Ready(Ok(value)) => {
+                        *this.done = true;
+                        return Poll::Ready(Ok(value));
+                    }
+                    Poll::Ready(Err(err)) => {
+                        this.errors[idx] = Some(err);
+                        *this.failed += 1;
+                        to_start += 1;
+                    }
+                    Poll::Pending => {}
+                }
+            }
+
+            if *this.failed == len {
+                *this.done = true;
+                let errors = this.errors.drain(..).map(Option::unwrap).collect();
+                return Poll::Ready(Err(errors));
+            }
+
+            if let Some(delay) = this.delay.as_mut().as_pin_mut() {
+                if delay.poll(cx).is_ready() {
+                    to_start += 1;
+                }
+            }
+
+            if to_start == 0 {
+                return Poll::Pending;
+            }
+        }
+    }
+}
+
+impl<Fut, T> FusedFuture for RaceOkStaggered<Fut, T>
+where
+    Fut: TryFuture,
+    T: Timer,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<Fut, V, E> RaceOkStaggeredTrait for Vec<Fut>
+where
+    Fut: IntoFuture<Output = Result<V, E>>,
+{
+    type Ok = V;
+    type Error = Vec<E>;
+    type Future<T>
+        = RaceOkStaggered<Fut::IntoFuture, T>
+    where
+        T: Timer;
+
+    fn race_ok_staggered<T>(self, timer: T, dur: Duration) -> Self::Future<T>
+    where
+        T: Timer,
+    {
+        RaceOkStaggered::new(
+            self.into_iter().map(IntoFuture::into_future).collect(),
+            timer,
+            dur,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::channel::local_channel;
+    use futures_lite::future::{block_on, poll_once, yield_now};
+    use futures_lite::prelude::*;
+    use std::cell::{Cell, RefCell};
+    use std::future;
+    use std::pin::pin;
+    use std::rc::Rc;
+
+    fn never(_: Duration) -> future::Pending<()> {
+        future::pending()
+    }
+
+    #[test]
+    fn all_err() {
+        block_on(async {
+            let res: Result<(), _> = vec![future::ready(Err("oops")), future::ready(Err("oh no"))]
+                .race_ok_staggered(never, Duration::ZERO)
+                .await;
+            assert_eq!(res, Err(vec!["oops", "oh no"]));
+        });
+    }
+
+    #[test]
+    fn empty() {
+        block_on(async {
+            let data: Vec<future::Ready<Result<(), ()>>> = vec![];
+            let res = data.race_ok_staggered(never, Duration::ZERO).await;
+            assert_eq!(res, Err(vec![]));
+        });
+    }
+
+    #[test]
+    fn starts_lazily() {
+        block_on(async {
+            let started = Rc::new(RefCell::new(vec![]));
+            let attempt = |idx: usize, ok: bool| {
+                let started = started.clone();
+                async move {
+                    started.borrow_mut().push(idx);
+                    match ok {
+                        true => Ok(idx),
+                        false => Err(idx),
+                    }
+                }
+            };
+            let futs = vec![attempt(0, false), attempt(1, true), attempt(2, true)];
+            let res = futs.race_ok_staggered(never, Duration::ZERO).await;
+            assert_eq!(res, Ok(1));
+            assert_eq!(*started.borrow(), vec![0, 1]);
+        });
+    }
+
+    /// The next attempt starts once the delay elapses, even if the previous
+    /// attempt is still in flight.
+    #[test]
+    fn starts_on_delay() {
+        block_on(async {
+            let (send, receive) = local_channel::<()>();
+            let receive = RefCell::new(Some(receive));
+            let (stall, mut stalled) = local_channel::<Result<usize, usize>>();
+            let stalled = async move { stalled.next().await.unwrap() };
+
+            let futs = vec![
+                Box::pin(stalled) as Pin<Box<dyn Future<Output = Result<usize, usize>>>>,
+                Box::pin(async { Ok(1) }),
+            ];
+            // Only the first delay elapses, once we send a message.
+            let timer = move |_| {
+                let receive = receive.borrow_mut().take();
+                async move {
+                    match receive {
+                        Some(mut receive) => drop(receive.next().await),
+                        None => future::pending().await,
+                    }
+                }
+            };
+            let race = futs.race_ok_staggered(timer, Duration::from_secs(1));
+            let (res, ()) = crate::future::Join::join((race, async { send.send(()) })).await;
+            assert_eq!(res, Ok(1));
+            drop(stall);
+        });
+    }
+
+    /// Attempts which fail in the same poll each start a replacement.
+    #[test]
+    fn starts_one_per_failure() {
+        block_on(async {
+            let failing = Rc::new(Cell::new(false));
+            let attempt = |idx: usize| {
+                let failing = failing.clone();
+                Box::pin(async move {
+                    while !failing.get() {
+                        yield_now().await;
+                    }
+                    Err(idx)
+                }) as Pin<Box<dyn Future<Output = Result<usize, usize>>>>
+            };
+            let mut futs: Vec<_> = (0..3).map(attempt).collect();
+            futs.extend((3..6).map(|_| Box::pin(future::pending()) as Pin<Box<_>>));
+
+            // Only the first two delays elapse right away.
+            let delays = Cell::new(0);
+            let timer = move |_| {
+                delays.set(delays.get() + 1);
+                let elapsed = delays.get() <= 2;
+                async move {
+                    if !elapsed {
+                        future::pending::<()>().await;
+                    }
+                }
+            };
+
+            let mut race = pin!(futs.race_ok_staggered(timer, Duration::from_secs(1)));
+            assert!(poll_once(race.as_mut()).await.is_none());
+            assert_eq!(race.started, 3);
+
+            failing.set(true);
+            assert!(poll_once(race.as_mut()).await.is_none());
+            assert_eq!(race.started, 6);
+        });
+    }
+}
@@ -0,0 +1,135 @@
+use super::super::common::{CombinatorArray, CombinatorBehaviorArray};
+use super::{Settle as SettleTrait, SettleBehavior, Settled};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::future::{Future, IntoFuture};
+
+/// Wait for all futures to complete, keeping the result of every future.
+///
+/// This `struct` is created by the [`settle`] method on the [`Settle`] trait. See
+/// its documentation for more.
+///
+/// [`settle`]: crate::future::Settle::settle
+/// [`Settle`]: crate::future::Settle
+pub type Settle<Fut, const N: usize> = CombinatorArray<Fut, SettleBehavior, N>;
+
+impl<T, E, Fut, const N: usize> CombinatorBehaviorArray<Fut, N> for SettleBehavior
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    type Output = Settled<[Result<T, E>; N]>;
+
+    type StoredItem = Result<T, E>;
+
+    fn maybe_return(
+        _idx: usize,
+        res: <Fut as Future>::Output,
+    ) -> Result<Self::StoredItem, Self::Output> {
+        Ok(res)
+    }
+
+    fn when_completed_arr(arr: [Self::StoredItem; N]) -> Self::Output {
+        Settled::new(arr)
+    }
+
+    const AWAITS_ALL: bool = true;
+}
+
+impl<T, E, Fut, const N: usize> SettleTrait for [Fut; N]
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    type Output = Settled<[Result<T, E>; N]>;
+    type Future = Settle<Fut::IntoFuture, N>;
+
+    fn settle(self) -> Self::Future {
+        Settle::new(self.map(IntoFuture::into_future))
+    }
+}
+
+impl<T, E, const N: usize> Settled<[Result<T, E>; N]> {
+    /// Returns an iterator over the values of the futures which succeeded.
+    pub fn oks(self) -> impl Iterator<Item = T> {
+        self.0.into_iter().filter_map(Result::ok)
+    }
+
+    /// Returns an iterator over the errors of the futures which failed.
+    pub fn errs(self) -> impl Iterator<Item = E> {
+        self.0.into_iter().filter_map(Result::err)
+    }
+
+    /// Splits the results into the values of the futures which succeeded, and
+    /// the errors of the futures which failed.
+    #[cfg(feature = "alloc")]
+    pub fn partition(self) -> (Vec<T>, Vec<E>) {
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+        for res in self.0 {
+            match res {
+                Ok(t) => oks.push(t),
+                Err(e) => errs.push(e),
+            }
+        }
+        (oks, errs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::cell::Cell;
+    use core::task::{Poll, Waker};
+    use std::future;
+
+    fn record_waker(slot: &Cell<Option<Waker>>) -> impl Future<Output = Result<(), ()>> + '_ {
+        future::poll_fn(move |cx| {
+            slot.set(Some(cx.waker().clone()));
+            Poll::Ready(Ok(()))
+        })
+    }
+
+    #[test]
+    fn all_ok() {
+        futures_lite::future::block_on(async {
+            let res = [
+                future::ready(Ok::<_, ()>("hello")),
+                future::ready(Ok("world")),
+            ]
+            .settle()
+            .await;
+            assert_eq!(res.into_inner(), [Ok("hello"), Ok("world")]);
+        })
+    }
+
+    #[test]
+    fn keeps_errors() {
+        futures_lite::future::block_on(async {
+            let res = [
+                future::ready(Err("oh no")),
+                future::ready(Ok("hello")),
+                future::ready(Err("oops")),
+            ]
+            .settle()
+            .await;
+            assert_eq!(res.clone().oks().collect::<Vec<_>>(), ["hello"]);
+            assert_eq!(res.errs().collect::<Vec<_>>(), ["oh no", "oops"]);
+        });
+    }
+
+    #[test]
+    fn small_settle_polls_all() {
+        futures_lite::future::block_on(async {
+            let parent = future::poll_fn(|cx| Poll::Ready(cx.waker().clone())).await;
+            let wakers = [Cell::new(None), Cell::new(None)];
+            [record_waker(&wakers[0]), record_waker(&wakers[1])]
+                .settle()
+                .await;
+
+            // Like a small join, the subfutures get the parent's waker.
+            for waker in wakers {
+                assert!(waker.into_inner().unwrap().will_wake(&parent));
+            }
+        });
+    }
+}
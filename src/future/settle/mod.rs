@@ -0,0 +1,80 @@
+use core::future::Future;
+use core::ops::Deref;
+
+pub(crate) mod array;
+pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Wait for all futures to complete, keeping the result of every future.
+///
+/// Unlike [`TryJoin`][super::TryJoin], this doesn't return early when a future
+/// fails. Every future is run to completion, and all of their results are
+/// returned as a [`Settled`] collection.
+pub trait Settle {
+    /// The resulting output type.
+    type Output;
+
+    /// Which kind of future are we turning this into?
+    type Future: Future<Output = Self::Output>;
+
+    /// Waits for multiple fallible futures to complete.
+    ///
+    /// Awaits multiple futures simultaneously, returning the result of every
+    /// future once all complete, regardless of whether they succeeded.
+    ///
+    /// This function returns a new future which polls all futures concurrently.
+    fn settle(self) -> Self::Future;
+}
+
+#[derive(Debug)]
+pub struct SettleBehavior;
+
+/// The results of a set of futures which have all completed.
+///
+/// This `struct` is returned by the [`settle`] method on the [`Settle`]
+/// trait. It dereferences to the underlying collection of results, and has
+/// helper methods to split the successes from the failures.
+///
+/// [`settle`]: Settle::settle
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_lite::future::block_on;
+/// use std::future;
+///
+/// block_on(async {
+///     let a = future::ready(Ok(1));
+///     let b = future::ready(Err("oh no"));
+///     let c = future::ready(Ok(3));
+///     let settled = [a, b, c].settle().await;
+///
+///     assert!(settled[1].is_err());
+///     let (oks, errs) = settled.partition();
+///     assert_eq!(oks, vec![1, 3]);
+///     assert_eq!(errs, vec!["oh no"]);
+/// })
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settled<R>(R);
+
+impl<R> Settled<R> {
+    pub(crate) fn new(results: R) -> Self {
+        Self(results)
+    }
+
+    /// Returns the underlying collection of results.
+    pub fn into_inner(self) -> R {
+        self.0
+    }
+}
+
+impl<R> Deref for Settled<R> {
+    type Target = R;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -0,0 +1,151 @@
+use super::super::common::{CombineTuple, MapResult};
+use super::{Settle as SettleTrait, Settled};
+
+use core::fmt::Debug;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::TryFuture;
+
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct SettleFuture<F: Future>(#[pin] F);
+impl<F: TryFuture> Future for SettleFuture<F> {
+    type Output = Result<Result<F::Ok, F::Error>, core::convert::Infallible>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().0.try_poll(cx).map(Ok)
+    }
+}
+
+#[derive(Debug)]
+pub struct MapResultSettle;
+impl<R, S> MapResult<Result<R, S>> for MapResultSettle {
+    type FinalResult = Settled<R>;
+    fn to_final_result(result: Result<R, S>) -> Self::FinalResult {
+        match result {
+            Err(_s) => unreachable!(),
+            Ok(r) => Settled::new(r),
+        }
+    }
+
+    const AWAITS_ALL: bool = true;
+}
+
+macro_rules! impl_settle_tuple {
+    ($($F:ident $T:ident $E:ident)+) => {
+        impl<$($F),+> SettleTrait for ($($F,)+)
+        where $(
+            $F: IntoFuture,
+            $F::IntoFuture: TryFuture,
+        )+ {
+            type Output = Settled<($(Result<<$F::IntoFuture as TryFuture>::Ok, <$F::IntoFuture as TryFuture>::Error>,)+)>;
+            type Future = <(($(SettleFuture<$F::IntoFuture>,)+), MapResultSettle) as CombineTuple>::Combined;
+            fn settle(self) -> Self::Future {
+                let ($($F,)+) = self;
+                (
+                    (
+                        $(SettleFuture($F.into_future()),)+
+                    ),
+                    MapResultSettle
+                ).combine()
+            }
+        }
+
+        impl<$($T, $E),+> Settled<($(Result<$T, $E>,)+)> {
+            /// Returns the value of each future which succeeded.
+            pub fn oks(self) -> ($(Option<$T>,)+) {
+                let ($($F,)+) = self.0;
+                ($($F.ok(),)+)
+            }
+
+            /// Returns the error of each future which failed.
+            pub fn errs(self) -> ($(Option<$E>,)+) {
+                let ($($F,)+) = self.0;
+                ($($F.err(),)+)
+            }
+
+            /// Splits the results into the values of the futures which
+            /// succeeded, and the errors of the futures which failed.
+            pub fn partition(self) -> (($(Option<$T>,)+), ($(Option<$E>,)+)) {
+                let ($($F,)+) = self.0;
+                $(
+                    let $F = match $F {
+                        Ok(t) => (Some(t), None),
+                        Err(e) => (None, Some(e)),
+                    };
+                )+
+                (($($F.0,)+), ($($F.1,)+))
+            }
+        }
+    };
+}
+
+impl_settle_tuple! { A0 T0 E0 }
+impl_settle_tuple! { A0 T0 E0 A1 T1 E1 }
+impl_settle_tuple! { A0 T0 E0 A1 T1 E1 A2 T2 E2 }
+impl_settle_tuple! { A0 T0 E0 A1 T1 E1 A2 T2 E2 A3 T3 E3 }
+impl_settle_tuple! { A0 T0 E0 A1 T1 E1 A2 T2 E2 A3 T3 E3 A4 T4 E4 }
+impl_settle_tuple! { A0 T0 E0 A1 T1 E1 A2 T2 E2 A3 T3 E3 A4 T4 E4 A5 T5 E5 }
+impl_settle_tuple! { A0 T0 E0 A1 T1 E1 A2 T2 E2 A3 T3 E3 A4 T4 E4 A5 T5 E5 A6 T6 E6 }
+impl_settle_tuple! { A0 T0 E0 A1 T1 E1 A2 T2 E2 A3 T3 E3 A4 T4 E4 A5 T5 E5 A6 T6 E6 A7 T7 E7 }
+impl_settle_tuple! { A0 T0 E0 A1 T1 E1 A2 T2 E2 A3 T3 E3 A4 T4 E4 A5 T5 E5 A6 T6 E6 A7 T7 E7 A8 T8 E8 }
+impl_settle_tuple! { A0 T0 E0 A1 T1 E1 A2 T2 E2 A3 T3 E3 A4 T4 E4 A5 T5 E5 A6 T6 E6 A7 T7 E7 A8 T8 E8 A9 T9 E9 }
+impl_settle_tuple! { A0 T0 E0 A1 T1 E1 A2 T2 E2 A3 T3 E3 A4 T4 E4 A5 T5 E5 A6 T6 E6 A7 T7 E7 A8 T8 E8 A9 T9 E9 A10 T10 E10 }
+impl_settle_tuple! { A0 T0 E0 A1 T1 E1 A2 T2 E2 A3 T3 E3 A4 T4 E4 A5 T5 E5 A6 T6 E6 A7 T7 E7 A8 T8 E8 A9 T9 E9 A10 T10 E10 A11 T11 E11 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::cell::Cell;
+    use core::task::Waker;
+    use std::future;
+    use std::io;
+
+    #[test]
+    fn settle_2() {
+        futures_lite::future::block_on(async {
+            let a = future::ready(Ok::<_, ()>(42));
+            let b = future::ready(Err::<&str, _>(io::Error::other("oh no")));
+            let (oks, errs) = (a, b).settle().await.partition();
+            assert_eq!(oks, (Some(42), None));
+            assert_eq!(errs.0, None);
+            assert_eq!(errs.1.unwrap().to_string(), "oh no");
+        })
+    }
+
+    #[test]
+    fn settle_3() {
+        futures_lite::future::block_on(async {
+            let a = future::ready(Ok::<_, ()>("hello"));
+            let b = future::ready(Err::<(), _>(12));
+            let c = future::ready(Ok::<_, ()>('c'));
+            let res = (a, b, c).settle().await;
+            assert_eq!(res.clone().oks(), (Some("hello"), None, Some('c')));
+            assert_eq!(res.errs(), (None, Some(12), None));
+        })
+    }
+
+    #[test]
+    fn small_settle_polls_all() {
+        futures_lite::future::block_on(async {
+            let parent = future::poll_fn(|cx| Poll::Ready(cx.waker().clone())).await;
+            let (a, b) = (Cell::new(None), Cell::new(None));
+            let record = |slot: &Cell<Option<Waker>>, cx: &mut Context<'_>| {
+                slot.set(Some(cx.waker().clone()));
+                Poll::Ready(Ok::<_, ()>(()))
+            };
+            (
+                future::poll_fn(|cx| record(&a, cx)),
+                future::poll_fn(|cx| record(&b, cx)),
+            )
+                .settle()
+                .await;
+
+            // Like a small join, the subfutures get the parent's waker.
+            assert!(a.into_inner().unwrap().will_wake(&parent));
+            assert!(b.into_inner().unwrap().will_wake(&parent));
+        })
+    }
+}
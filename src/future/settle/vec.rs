@@ -0,0 +1,108 @@
+use super::super::common::{CombinatorBehaviorVec, CombinatorVec};
+use super::{Settle as SettleTrait, SettleBehavior, Settled};
+
+use alloc::vec::Vec;
+use core::future::{Future, IntoFuture};
+
+/// Wait for all futures to complete, keeping the result of every future.
+///
+/// This `struct` is created by the [`settle`] method on the [`Settle`] trait. See
+/// its documentation for more.
+///
+/// [`settle`]: crate::future::Settle::settle
+/// [`Settle`]: crate::future::Settle
+pub type Settle<Fut> = CombinatorVec<Fut, SettleBehavior>;
+
+impl<T, E, Fut> CombinatorBehaviorVec<Fut> for SettleBehavior
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    type Output = Settled<Vec<Result<T, E>>>;
+
+    type StoredItem = Result<T, E>;
+
+    fn maybe_return(
+        _idx: usize,
+        res: <Fut as Future>::Output,
+    ) -> Result<Self::StoredItem, Self::Output> {
+        Ok(res)
+    }
+
+    fn when_completed_vec(vec: Vec<Self::StoredItem>) -> Self::Output {
+        Settled::new(vec)
+    }
+}
+
+impl<T, E, Fut> SettleTrait for Vec<Fut>
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    type Output = Settled<Vec<Result<T, E>>>;
+    type Future = Settle<Fut::IntoFuture>;
+
+    fn settle(self) -> Self::Future {
+        Settle::new(self.into_iter().map(IntoFuture::into_future).collect())
+    }
+}
+
+impl<T, E> Settled<Vec<Result<T, E>>> {
+    /// Returns an iterator over the values of the futures which succeeded.
+    pub fn oks(self) -> impl Iterator<Item = T> {
+        self.0.into_iter().filter_map(Result::ok)
+    }
+
+    /// Returns an iterator over the errors of the futures which failed.
+    pub fn errs(self) -> impl Iterator<Item = E> {
+        self.0.into_iter().filter_map(Result::err)
+    }
+
+    /// Splits the results into the values of the futures which succeeded, and
+    /// the errors of the futures which failed.
+    pub fn partition(self) -> (Vec<T>, Vec<E>) {
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+        for res in self.0 {
+            match res {
+                Ok(t) => oks.push(t),
+                Err(e) => errs.push(e),
+            }
+        }
+        (oks, errs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::future;
+
+    #[test]
+    fn all_err() {
+        futures_lite::future::block_on(async {
+            let res = vec![
+                future::ready(Err::<(), _>("oops")),
+                future::ready(Err("oh no")),
+            ]
+            .settle()
+            .await;
+            assert_eq!(res.len(), 2);
+            assert_eq!(res.oks().count(), 0);
+        })
+    }
+
+    #[test]
+    fn partition() {
+        futures_lite::future::block_on(async {
+            let res = vec![
+                future::ready(Err("oh no")),
+                future::ready(Ok("hello")),
+                future::ready(Ok("world")),
+            ]
+            .settle()
+            .await;
+            let (oks, errs) = res.partition();
+            assert_eq!(oks, vec!["hello", "world"]);
+            assert_eq!(errs, vec!["oh no"]);
+        });
+    }
+}
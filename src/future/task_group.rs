@@ -0,0 +1,619 @@
+//! A scope which awaits a dynamic set of fallible tasks.
+
+use super::future_group::{FutureGroup, Key};
+
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt;
+use core::future::Future;
+use core::mem;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use futures_core::future::FusedFuture;
+use futures_core::Stream;
+
+type BoxTask<'a, T, E> = Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'a>>;
+type LocalBoxTask<'a, T, E> = Pin<Box<dyn Future<Output = Result<T, E>> + 'a>>;
+type SharedTasks<'a, T, E> = Arc<Mutex<Shared<BoxTask<'a, T, E>>>>;
+type LocalSharedTasks<'a, T, E> = Rc<RefCell<Shared<LocalBoxTask<'a, T, E>>>>;
+
+/// A scope which awaits a dynamic set of fallible tasks.
+///
+/// Tasks are added through [`spawn`][TaskGroup::spawn], or through a
+/// [`Spawner`] handle which can be moved into the tasks themselves. The group
+/// completes once every task has completed successfully, returning their
+/// outputs in the order they were spawned.
+///
+/// When a task returns an error, all other tasks are cancelled and the error is
+/// returned. Cancelled tasks are dropped in the order they were spawned, as
+/// they are when the group itself is dropped before completing. Tasks spawned
+/// after the group has completed are dropped without being polled.
+///
+/// Tasks must be `Send`, so the group can be moved between threads. Use
+/// [`LocalTaskGroup`] for tasks which aren't.
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::future::TaskGroup;
+/// use std::future;
+///
+/// futures_lite::future::block_on(async {
+///     let group = TaskGroup::new();
+///     let spawner = group.spawner();
+///     group.spawn(async move {
+///         spawner.spawn(future::ready(Ok(2)));
+///         Ok::<_, ()>(1)
+///     });
+///
+///     assert_eq!(group.await, Ok(vec![1, 2]));
+/// })
+/// ```
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TaskGroup<'a, T, E> {
+    inner: Group<T, BoxTask<'a, T, E>, SharedTasks<'a, T, E>>,
+}
+
+/// A handle used to spawn tasks into a [`TaskGroup`].
+///
+/// This `struct` is created by the [`spawner`] method on [`TaskGroup`]. See its
+/// documentation for more.
+///
+/// [`spawner`]: TaskGroup::spawner
+pub struct Spawner<'a, T, E> {
+    shared: SharedTasks<'a, T, E>,
+}
+
+/// A scope which awaits a dynamic set of fallible tasks, which don't need to
+/// be `Send`.
+///
+/// This is the same as [`TaskGroup`], except that neither the tasks nor the
+/// group can be moved between threads. See its documentation for more.
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::future::LocalTaskGroup;
+/// use std::rc::Rc;
+///
+/// futures_lite::future::block_on(async {
+///     let group = LocalTaskGroup::new();
+///     let spawner = group.spawner();
+///     let shared = Rc::new(2);
+///     group.spawn(async move {
+///         spawner.spawn(async move { Ok(*shared) });
+///         Ok::<_, ()>(1)
+///     });
+///
+///     assert_eq!(group.await, Ok(vec![1, 2]));
+/// })
+/// ```
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct LocalTaskGroup<'a, T, E> {
+    inner: Group<T, LocalBoxTask<'a, T, E>, LocalSharedTasks<'a, T, E>>,
+}
+
+/// A handle used to spawn tasks into a [`LocalTaskGroup`].
+///
+/// This `struct` is created by the [`spawner`] method on [`LocalTaskGroup`].
+/// See its documentation for more.
+///
+/// [`spawner`]: LocalTaskGroup::spawner
+pub struct LocalSpawner<'a, T, E> {
+    shared: LocalSharedTasks<'a, T, E>,
+}
+
+/// The group itself, shared by [`TaskGroup`] and [`LocalTaskGroup`].
+struct Group<T, P, H>
+where
+    P: Future + Unpin,
+    H: SharedHandle<P>,
+{
+    group: FutureGroup<Task<P>>,
+    /// Keys of the tasks in `group`, ordered by when they were spawned.
+    keys: BTreeMap<usize, Key>,
+    /// Outputs of the completed tasks, indexed by when they were spawned.
+    outputs: Vec<Option<T>>,
+    shared: H,
+    done: bool,
+}
+
+/// State shared between the group and its spawners.
+struct Shared<P> {
+    /// Tasks which have been spawned, but not yet inserted into the group.
+    queue: VecDeque<P>,
+    /// Number of tasks spawned so far.
+    spawned: usize,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+/// A handle to the state shared between the group and its spawners: an
+/// `Arc<Mutex<_>>` for [`TaskGroup`], and an `Rc<RefCell<_>>` for
+/// [`LocalTaskGroup`].
+trait SharedHandle<P>: Clone {
+    fn new(shared: Shared<P>) -> Self;
+
+    /// Run `f` with exclusive access to the shared state.
+    fn with<R>(&self, f: impl FnOnce(&mut Shared<P>) -> R) -> R;
+}
+
+impl<P> SharedHandle<P> for Arc<Mutex<Shared<P>>> {
+    fn new(shared: Shared<P>) -> Self {
+        Arc::new(Mutex::new(shared))
+    }
+
+    fn with<R>(&self, f: impl FnOnce(&mut Shared<P>) -> R) -> R {
+        f(&mut self.lock().unwrap())
+    }
+}
+
+impl<P> SharedHandle<P> for Rc<RefCell<Shared<P>>> {
+    fn new(shared: Shared<P>) -> Self {
+        Rc::new(RefCell::new(shared))
+    }
+
+    fn with<R>(&self, f: impl FnOnce(&mut Shared<P>) -> R) -> R {
+        f(&mut self.borrow_mut())
+    }
+}
+
+/// A task, tagged with when it was spawned.
+struct Task<P> {
+    index: usize,
+    future: P,
+}
+
+impl<P> Future for Task<P>
+where
+    P: Future + Unpin,
+{
+    type Output = (usize, P::Output);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let index = self.index;
+        Pin::new(&mut self.future).poll(cx).map(|res| (index, res))
+    }
+}
+
+impl<P> Shared<P> {
+    /// Queue a task, returning it back if the group has completed.
+    fn push(&mut self, task: P) -> Result<Option<Waker>, P> {
+        if self.closed {
+            return Err(task);
+        }
+        self.queue.push_back(task);
+        Ok(self.waker.clone())
+    }
+}
+
+impl<T, P, H> Group<T, P, H>
+where
+    P: Future + Unpin,
+    H: SharedHandle<P>,
+{
+    fn new() -> Self {
+        Self {
+            group: FutureGroup::new(),
+            keys: BTreeMap::new(),
+            outputs: Vec::new(),
+            shared: H::new(Shared {
+                queue: VecDeque::new(),
+                spawned: 0,
+                waker: None,
+                closed: false,
+            }),
+            done: false,
+        }
+    }
+
+    /// Move the queued tasks into the group.
+    ///
+    /// Returns `false` once there is nothing left to run, after which no more
+    /// tasks are accepted.
+    fn insert_queued(&mut self) -> bool {
+        let group_is_empty = self.group.is_empty();
+        let queued = self.shared.with(|shared| {
+            if shared.queue.is_empty() && group_is_empty {
+                shared.closed = true;
+                return None;
+            }
+            let start = shared.spawned;
+            shared.spawned += shared.queue.len();
+            Some((start, mem::take(&mut shared.queue)))
+        });
+        let Some((start, queue)) = queued else {
+            return false;
+        };
+        for (index, future) in (start..).zip(queue) {
+            let key = self.group.insert(Task { index, future });
+            self.keys.insert(index, key);
+            self.outputs.push(None);
+        }
+        true
+    }
+
+    /// Drop all remaining tasks, in the order they were spawned.
+    fn cancel(&mut self) {
+        let queue = self.shared.with(|shared| {
+            shared.closed = true;
+            mem::take(&mut shared.queue)
+        });
+        // Tasks may spawn more tasks when dropped, so we don't drop them while
+        // holding the lock.
+        for key in mem::take(&mut self.keys).into_values() {
+            self.group.remove(key);
+        }
+        for task in queue {
+            drop(task);
+        }
+    }
+
+    fn fmt(&self, name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name)
+            .field("running", &self.group.len())
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<T, E, P, H> Group<T, P, H>
+where
+    P: Future<Output = Result<T, E>> + Unpin,
+    H: SharedHandle<P>,
+{
+    fn poll(&mut self, cx: &mut Context<'_>) -> Poll<Result<Vec<T>, E>> {
+        assert!(!self.done, "Futures must not be polled after completing");
+
+        // Register the waker first, so tasks spawned from outside the group
+        // after we've drained the queue will wake us.
+        self.shared
+            .with(|shared| shared.waker = Some(cx.waker().clone()));
+
+        while self.insert_queued() {
+            match Pin::new(&mut self.group).poll_next(cx) {
+                Poll::Ready(Some((index, Ok(output)))) => {
+                    self.keys.remove(&index);
+                    self.outputs[index] = Some(output);
+                }
+                Poll::Ready(Some((index, Err(err)))) => {
+                    self.keys.remove(&index);
+                    self.cancel();
+                    self.done = true;
+                    return Poll::Ready(Err(err));
+                }
+                // Tasks spawned while polling need to be inserted before
+                // we can go to sleep.
+                Poll::Ready(None) => {}
+                Poll::Pending => {
+                    if self.shared.with(|shared| shared.queue.is_empty()) {
+                        return Poll::Pending;
+                    }
+                }
+            }
+        }
+
+        self.done = true;
+        let outputs = mem::take(&mut self.outputs);
+        Poll::Ready(Ok(outputs.into_iter().map(Option::unwrap).collect()))
+    }
+}
+
+// The tasks are boxed, so nothing needs to stay pinned.
+impl<T, P, H> Unpin for Group<T, P, H>
+where
+    P: Future + Unpin,
+    H: SharedHandle<P>,
+{
+}
+
+impl<T, P, H> Drop for Group<T, P, H>
+where
+    P: Future + Unpin,
+    H: SharedHandle<P>,
+{
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+fn spawn<P>(shared: &impl SharedHandle<P>, task: P) {
+    let res = shared.with(|shared| shared.push(task));
+    // Wake and drop outside of the lock, since either may run arbitrary code.
+    match res {
+        Ok(Some(waker)) => waker.wake(),
+        Ok(None) => {}
+        Err(task) => drop(task),
+    }
+}
+
+impl<'a, T, E> TaskGroup<'a, T, E> {
+    /// Create a new, empty instance of `TaskGroup`.
+    pub fn new() -> Self {
+        Self {
+            inner: Group::new(),
+        }
+    }
+
+    /// Create a handle which can spawn tasks into this group.
+    pub fn spawner(&self) -> Spawner<'a, T, E> {
+        Spawner {
+            shared: self.inner.shared.clone(),
+        }
+    }
+
+    /// Spawn a task into the group.
+    pub fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = Result<T, E>> + Send + 'a,
+    {
+        spawn(&self.inner.shared, Box::pin(future) as BoxTask<'a, T, E>);
+    }
+}
+
+impl<T, E> Default for TaskGroup<'_, T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E> fmt::Debug for TaskGroup<'_, T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt("TaskGroup", f)
+    }
+}
+
+impl<T, E> Future for TaskGroup<'_, T, E> {
+    type Output = Result<Vec<T>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.get_mut().inner.poll(cx)
+    }
+}
+
+impl<T, E> FusedFuture for TaskGroup<'_, T, E> {
+    fn is_terminated(&self) -> bool {
+        self.inner.done
+    }
+}
+
+impl<'a, T, E> Spawner<'a, T, E> {
+    /// Spawn a task into the group.
+    ///
+    /// The task is dropped without being polled if the group has already
+    /// completed, or was dropped.
+    pub fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = Result<T, E>> + Send + 'a,
+    {
+        spawn(&self.shared, Box::pin(future) as BoxTask<'a, T, E>);
+    }
+}
+
+impl<T, E> Clone for Spawner<'_, T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T, E> fmt::Debug for Spawner<'_, T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Spawner").finish_non_exhaustive()
+    }
+}
+
+impl<'a, T, E> LocalTaskGroup<'a, T, E> {
+    /// Create a new, empty instance of `LocalTaskGroup`.
+    pub fn new() -> Self {
+        Self {
+            inner: Group::new(),
+        }
+    }
+
+    /// Create a handle which can spawn tasks into this group.
+    pub fn spawner(&self) -> LocalSpawner<'a, T, E> {
+        LocalSpawner {
+            shared: self.inner.shared.clone(),
+        }
+    }
+
+    /// Spawn a task into the group.
+    pub fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = Result<T, E>> + 'a,
+    {
+        spawn(
+            &self.inner.shared,
+            Box::pin(future) as LocalBoxTask<'a, T, E>,
+        );
+    }
+}
+
+impl<T, E> Default for LocalTaskGroup<'_, T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E> fmt::Debug for LocalTaskGroup<'_, T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt("LocalTaskGroup", f)
+    }
+}
+
+impl<T, E> Future for LocalTaskGroup<'_, T, E> {
+    type Output = Result<Vec<T>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.get_mut().inner.poll(cx)
+    }
+}
+
+impl<T, E> FusedFuture for LocalTaskGroup<'_, T, E> {
+    fn is_terminated(&self) -> bool {
+        self.inner.done
+    }
+}
+
+impl<'a, T, E> LocalSpawner<'a, T, E> {
+    /// Spawn a task into the group.
+    ///
+    /// The task is dropped without being polled if the group has already
+    /// completed, or was dropped.
+    pub fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = Result<T, E>> + 'a,
+    {
+        spawn(&self.shared, Box::pin(future) as LocalBoxTask<'a, T, E>);
+    }
+}
+
+impl<T, E> Clone for LocalSpawner<'_, T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T, E> fmt::Debug for LocalSpawner<'_, T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalSpawner").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures_lite::future::{block_on, yield_now};
+    use std::future;
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let group = TaskGroup::new();
+            group.spawn(future::ready(Ok::<_, ()>(1)));
+            group.spawn(async {
+                yield_now().await;
+                Ok(2)
+            });
+            group.spawn(future::ready(Ok(3)));
+            assert_eq!(group.await, Ok(vec![1, 2, 3]));
+        });
+    }
+
+    #[test]
+    fn empty() {
+        block_on(async {
+            let group = TaskGroup::<(), ()>::new();
+            assert_eq!(group.await, Ok(vec![]));
+        });
+    }
+
+    #[test]
+    fn spawn_from_children() {
+        fn countdown(
+            spawner: Spawner<'static, usize, ()>,
+            n: usize,
+        ) -> BoxTask<'static, usize, ()> {
+            Box::pin(async move {
+                yield_now().await;
+                if n > 0 {
+                    spawner.spawn(countdown(spawner.clone(), n - 1));
+                }
+                Ok(n)
+            })
+        }
+
+        block_on(async {
+            let group = TaskGroup::new();
+            group.spawn(countdown(group.spawner(), 3));
+            assert_eq!(group.await, Ok(vec![3, 2, 1, 0]));
+        });
+    }
+
+    #[test]
+    fn error_cancels_in_spawn_order() {
+        struct OnDrop<'a>(&'a Mutex<Vec<usize>>, usize);
+        impl Drop for OnDrop<'_> {
+            fn drop(&mut self) {
+                self.0.lock().unwrap().push(self.1);
+            }
+        }
+
+        let dropped = Mutex::new(vec![]);
+        block_on(async {
+            let mut group = TaskGroup::new();
+            group.spawn(future::ready(Ok(())));
+            group.spawn(future::ready(Ok(())));
+            group.spawn(future::pending());
+            // Complete the first tasks, so the next ones reuse their slots out
+            // of order.
+            assert!(futures_lite::future::poll_once(&mut group).await.is_none());
+
+            for i in 0..4 {
+                let guard = OnDrop(&dropped, i);
+                group.spawn(async move {
+                    let _guard = guard;
+                    future::pending::<Result<(), &str>>().await
+                });
+            }
+            group.spawn(async {
+                yield_now().await;
+                Err("oh no")
+            });
+            assert_eq!(group.await, Err("oh no"));
+        });
+        assert_eq!(*dropped.lock().unwrap(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn spawn_after_completion() {
+        block_on(async {
+            let mut group = TaskGroup::new();
+            let spawner = group.spawner();
+            group.spawn(future::ready(Ok::<_, ()>(1)));
+            assert_eq!((&mut group).await, Ok(vec![1]));
+            assert!(group.is_terminated());
+
+            let (send, receive) = futures::channel::oneshot::channel::<()>();
+            spawner.spawn(async move {
+                let _send = send;
+                Ok(2)
+            });
+            // The task was dropped, and with it the sender.
+            assert!(receive.await.is_err());
+        });
+    }
+
+    #[test]
+    fn local_tasks() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        block_on(async {
+            let log = Rc::new(RefCell::new(vec![]));
+            let group = LocalTaskGroup::new();
+            let spawner = group.spawner();
+            let child_log = log.clone();
+            group.spawn(async move {
+                yield_now().await;
+                child_log.borrow_mut().push(1);
+                spawner.spawn(async move {
+                    child_log.borrow_mut().push(2);
+                    Ok(2)
+                });
+                Ok::<_, ()>(1)
+            });
+            assert_eq!(group.await, Ok(vec![1, 2]));
+            assert_eq!(*log.borrow(), vec![1, 2]);
+        });
+    }
+}
@@ -0,0 +1,443 @@
+use super::{Join, Race, TryJoin};
+
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+
+use futures_core::future::FusedFuture;
+use pin_project::pin_project;
+
+/// A source of delays, used to create deadlines.
+///
+/// This trait allows any runtime to plug its own timer into the timeout
+/// operations. It is implemented for all closures which take a [`Duration`]
+/// and return a future which completes once that duration has elapsed.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::future::Timer;
+/// use std::time::Duration;
+///
+/// fn assert_timer(_: impl Timer) {}
+/// assert_timer(|dur: Duration| async move {
+///     futures_time::task::sleep(dur.into()).await;
+/// });
+/// ```
+pub trait Timer {
+    /// The future which completes once the delay has elapsed.
+    type Delay: Future<Output = ()>;
+
+    /// Create a future which completes after the given duration.
+    fn delay(&self, dur: Duration) -> Self::Delay;
+}
+
+impl<F, D> Timer for F
+where
+    F: Fn(Duration) -> D,
+    D: Future<Output = ()>,
+{
+    type Delay = D;
+
+    fn delay(&self, dur: Duration) -> Self::Delay {
+        (self)(dur)
+    }
+}
+
+/// A future which can hand out the outputs of the subfutures which have
+/// already completed.
+///
+/// This is implemented by the array and vector futures created by
+/// [`Join`], [`TryJoin`], and [`Race`], which makes them usable with the
+/// timeout operations. This trait is sealed, and cannot be implemented
+/// outside of this crate.
+pub trait PartialFuture: Future + private::Sealed {
+    /// The partial output. For every subfuture this holds `Some` if the
+    /// subfuture completed, and `None` if it was still pending.
+    type Partial;
+
+    /// Take the outputs of the subfutures which have completed so far.
+    fn take_partial(self: Pin<&mut Self>) -> Self::Partial;
+}
+
+pub(crate) mod private {
+    /// Prevents [`super::PartialFuture`] from being implemented outside of
+    /// this crate.
+    pub trait Sealed {}
+}
+
+/// The error returned when a deadline elapses before a set of futures
+/// completes.
+///
+/// This holds the partial outputs of the set: an `Option` for every future,
+/// which is `Some` if that future had completed before the deadline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedOut<P> {
+    partial: P,
+}
+
+impl<P> TimedOut<P> {
+    /// Returns a reference to the partial outputs.
+    pub fn partial(&self) -> &P {
+        &self.partial
+    }
+
+    /// Returns the partial outputs.
+    pub fn into_partial(self) -> P {
+        self.partial
+    }
+
+    /// Returns the indices of the futures which were still pending when the
+    /// deadline elapsed.
+    pub fn pending<'a, T: 'a>(&'a self) -> impl Iterator<Item = usize> + 'a
+    where
+        P: AsRef<[Option<T>]>,
+    {
+        self.partial
+            .as_ref()
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, item)| item.is_none().then_some(idx))
+    }
+}
+
+impl<P> fmt::Display for TimedOut<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("deadline has elapsed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P: fmt::Debug> std::error::Error for TimedOut<P> {}
+
+/// A future which waits for a set of futures, or until a deadline elapses.
+///
+/// This `struct` is created by the timeout methods such as
+/// [`join_timeout`][JoinTimeout::join_timeout]. See their documentation for
+/// more.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+#[derive(Debug)]
+pub struct Timeout<C, D> {
+    #[pin]
+    inner: C,
+    #[pin]
+    deadline: D,
+    done: bool,
+}
+
+impl<C, D> Timeout<C, D> {
+    pub(crate) fn new(inner: C, deadline: D) -> Self {
+        Self {
+            inner,
+            deadline,
+            done: false,
+        }
+    }
+}
+
+impl<C, D> Future for Timeout<C, D>
+where
+    C: PartialFuture,
+    D: Future<Output = ()>,
+{
+    type Output = Result<C::Output, TimedOut<C::Partial>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        assert!(!*this.done, "Futures must not be polled after completing");
+
+        if let Poll::Ready(output) = this.inner.as_mut().poll(cx) {
+            *this.done = true;
+            return Poll::Ready(Ok(output));
+        }
+
+        match this.deadline.poll(cx) {
+            Poll::Ready(()) => {
+                *this.done = true;
+                let partial = this.inner.take_partial();
+                Poll::Ready(Err(TimedOut { partial }))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<C, D> FusedFuture for Timeout<C, D>
+where
+    C: PartialFuture,
+    D: Future<Output = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+/// Wait for all futures to complete, or until a deadline elapses.
+///
+/// This is implemented for arrays and vectors of futures.
+pub trait JoinTimeout {
+    /// Which kind of future are we waiting on?
+    type Future: PartialFuture;
+
+    /// Waits for multiple futures to complete, or until the deadline elapses.
+    ///
+    /// If the deadline elapses first, the remaining futures are cancelled and
+    /// a [`TimedOut`] error is returned holding the outputs of the futures
+    /// which did complete.
+    ///
+    /// A deadline can be any future, such as one created using a [`Timer`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::future::{JoinTimeout, Timer};
+    /// use futures_lite::future::block_on;
+    /// use std::time::Duration;
+    ///
+    /// async fn sleep(dur: Duration) {
+    ///     futures_time::task::sleep(dur.into()).await;
+    /// }
+    ///
+    /// async fn after(millis: u64, n: u8) -> u8 {
+    ///     sleep(Duration::from_millis(millis)).await;
+    ///     n
+    /// }
+    ///
+    /// block_on(async {
+    ///     let err = [after(0, 1), after(1_000, 2)]
+    ///         .join_timeout(sleep.delay(Duration::from_millis(100)))
+    ///         .await
+    ///         .unwrap_err();
+    ///     assert_eq!(err.partial(), &[Some(1), None]);
+    ///     assert_eq!(err.pending().collect::<Vec<_>>(), vec![1]);
+    /// })
+    /// ```
+    fn join_timeout<D>(self, deadline: D) -> Timeout<Self::Future, D::IntoFuture>
+    where
+        D: IntoFuture<Output = ()>;
+
+    /// Waits for multiple futures to complete, or until `dur` has elapsed on
+    /// the given timer.
+    ///
+    /// This is a shorthand for `self.join_timeout(timer.delay(dur))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::future::JoinTimeout;
+    /// use futures_lite::future::block_on;
+    /// use std::time::Duration;
+    ///
+    /// async fn sleep(dur: Duration) {
+    ///     futures_time::task::sleep(dur.into()).await;
+    /// }
+    ///
+    /// async fn after(millis: u64, n: u8) -> u8 {
+    ///     sleep(Duration::from_millis(millis)).await;
+    ///     n
+    /// }
+    ///
+    /// block_on(async {
+    ///     let err = [after(0, 1), after(1_000, 2)]
+    ///         .join_timeout_with(sleep, Duration::from_millis(100))
+    ///         .await
+    ///         .unwrap_err();
+    ///     assert_eq!(err.partial(), &[Some(1), None]);
+    /// })
+    /// ```
+    fn join_timeout_with<T>(self, timer: T, dur: Duration) -> Timeout<Self::Future, T::Delay>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        self.join_timeout(timer.delay(dur))
+    }
+}
+
+impl<T> JoinTimeout for T
+where
+    T: Join,
+    T::Future: PartialFuture,
+{
+    type Future = T::Future;
+
+    fn join_timeout<D>(self, deadline: D) -> Timeout<Self::Future, D::IntoFuture>
+    where
+        D: IntoFuture<Output = ()>,
+    {
+        Timeout::new(self.join(), deadline.into_future())
+    }
+}
+
+/// Wait for all futures to complete successfully, abort early on error, or
+/// until a deadline elapses.
+///
+/// This is implemented for arrays and vectors of futures.
+pub trait TryJoinTimeout {
+    /// Which kind of future are we waiting on?
+    type Future: PartialFuture;
+
+    /// Waits for multiple futures to complete successfully, or until the
+    /// deadline elapses.
+    ///
+    /// If the deadline elapses first, the remaining futures are cancelled and
+    /// a [`TimedOut`] error is returned holding the outputs of the futures
+    /// which did complete.
+    fn try_join_timeout<D>(self, deadline: D) -> Timeout<Self::Future, D::IntoFuture>
+    where
+        D: IntoFuture<Output = ()>;
+
+    /// Waits for multiple futures to complete successfully, or until `dur`
+    /// has elapsed on the given timer.
+    ///
+    /// This is a shorthand for `self.try_join_timeout(timer.delay(dur))`.
+    fn try_join_timeout_with<T>(self, timer: T, dur: Duration) -> Timeout<Self::Future, T::Delay>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        self.try_join_timeout(timer.delay(dur))
+    }
+}
+
+impl<T> TryJoinTimeout for T
+where
+    T: TryJoin,
+    T::Future: PartialFuture,
+{
+    type Future = T::Future;
+
+    fn try_join_timeout<D>(self, deadline: D) -> Timeout<Self::Future, D::IntoFuture>
+    where
+        D: IntoFuture<Output = ()>,
+    {
+        Timeout::new(self.try_join(), deadline.into_future())
+    }
+}
+
+/// Wait for the first future to complete, or until a deadline elapses.
+///
+/// This is implemented for arrays and vectors of futures.
+pub trait RaceTimeout {
+    /// Which kind of future are we waiting on?
+    type Future: PartialFuture;
+
+    /// Waits for the first future to complete, or until the deadline elapses.
+    ///
+    /// If the deadline elapses first, all futures are cancelled and a
+    /// [`TimedOut`] error is returned. Because no future completed, every
+    /// index is listed as pending.
+    fn race_timeout<D>(self, deadline: D) -> Timeout<Self::Future, D::IntoFuture>
+    where
+        D: IntoFuture<Output = ()>;
+
+    /// Waits for the first future to complete, or until `dur` has elapsed on
+    /// the given timer.
+    ///
+    /// This is a shorthand for `self.race_timeout(timer.delay(dur))`.
+    fn race_timeout_with<T>(self, timer: T, dur: Duration) -> Timeout<Self::Future, T::Delay>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        self.race_timeout(timer.delay(dur))
+    }
+}
+
+impl<T> RaceTimeout for T
+where
+    T: Race,
+    T::Future: PartialFuture,
+{
+    type Future = T::Future;
+
+    fn race_timeout<D>(self, deadline: D) -> Timeout<Self::Future, D::IntoFuture>
+    where
+        D: IntoFuture<Output = ()>,
+    {
+        Timeout::new(self.race(), deadline.into_future())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::channel::local_channel;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use std::future;
+    use std::io;
+
+    #[test]
+    fn join_completes() {
+        block_on(async {
+            let res = [future::ready(1), future::ready(2)]
+                .join_timeout(future::pending())
+                .await;
+            assert_eq!(res, Ok([1, 2]));
+        });
+    }
+
+    #[test]
+    fn join_times_out() {
+        block_on(async {
+            let (send, mut receive) = local_channel::<()>();
+            let deadline = async move {
+                let _ = receive.next().await;
+            };
+            let futs = [
+                Box::pin(async { 1_usize }) as Pin<Box<dyn Future<Output = usize>>>,
+                Box::pin(future::pending()),
+                Box::pin(async { 3 }),
+            ];
+            let timeout = futs.join_timeout(deadline);
+            let (res, ()) = crate::future::Join::join((timeout, async { send.send(()) })).await;
+            let err = res.unwrap_err();
+            assert_eq!(err.partial(), &[Some(1), None, Some(3)]);
+            assert_eq!(err.pending().collect::<Vec<_>>(), vec![1]);
+        });
+    }
+
+    #[test]
+    fn try_join_times_out() {
+        block_on(async {
+            let futs = [
+                Box::pin(async { Ok("hello") }) as Pin<Box<dyn Future<Output = io::Result<&str>>>>,
+                Box::pin(future::pending()),
+            ];
+            let err = futs.try_join_timeout(future::ready(())).await.unwrap_err();
+            assert_eq!(err.into_partial(), [Some("hello"), None]);
+        });
+    }
+
+    #[test]
+    fn race_times_out() {
+        block_on(async {
+            let futs = [future::pending::<()>(), future::pending()];
+            let err = futs.race_timeout(future::ready(())).await.unwrap_err();
+            assert_eq!(err.pending().collect::<Vec<_>>(), vec![0, 1]);
+        });
+    }
+
+    #[test]
+    fn timer_receives_duration() {
+        block_on(async {
+            let timer = |dur: Duration| {
+                assert_eq!(dur, Duration::from_secs(3));
+                future::ready(())
+            };
+            let futs = [future::pending::<()>(), future::pending()];
+            let err = futs.race_timeout_with(timer, Duration::from_secs(3)).await;
+            assert!(err.is_err());
+
+            let res = [future::ready(1)]
+                .join_timeout_with(timer, Duration::from_secs(3))
+                .await;
+            assert_eq!(res, Ok([1]));
+        });
+    }
+}
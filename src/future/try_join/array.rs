@@ -1,4 +1,4 @@
-use super::super::common::{CombinatorArray, CombinatorBehaviorArray};
+use super::super::common::{CombinatorArray, CombinatorArrayStream, CombinatorBehaviorArray};
 use super::{TryJoin as TryJoinTrait, TryJoinBehavior};
 use core::future::{Future, IntoFuture};
 
@@ -11,6 +11,53 @@ use core::future::{Future, IntoFuture};
 /// [`TryJoin`]: crate::future::TryJoin
 pub type TryJoin<Fut, const N: usize> = CombinatorArray<Fut, TryJoinBehavior, N>;
 
+/// A stream which yields the output of each future as soon as it completes,
+/// up to and including the first error.
+///
+/// This `struct` is created by the `into_stream` method on [`TryJoin`]. See its
+/// documentation for more.
+pub type TryJoinStream<Fut, const N: usize> = CombinatorArrayStream<Fut, TryJoinBehavior, N>;
+
+impl<T, E, Fut, const N: usize> TryJoin<Fut, N>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    /// Convert this future into a stream which yields the output of each
+    /// subfuture as soon as it completes, along with the subfuture's index.
+    ///
+    /// The stream ends once all subfutures have completed, or right after it
+    /// yields the first error, just like awaiting the combinator returns early
+    /// on it. Drop the stream to cancel the remaining subfutures.
+    ///
+    /// To also get the output the combinator would have resolved to, call
+    /// [`keep_outputs`][TryJoinStream::keep_outputs] on the stream and
+    /// [`finish`][TryJoinStream::finish] it once it's exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    /// use futures_lite::StreamExt;
+    /// use std::future;
+    /// use std::pin::pin;
+    ///
+    /// block_on(async {
+    ///     let mut s = pin!([future::ready(Ok::<_, ()>(1)), future::ready(Ok(2))].try_join().into_stream().keep_outputs());
+    ///
+    ///     let mut seen = vec![];
+    ///     while let Some((index, output)) = s.next().await {
+    ///         seen.push((index, output));
+    ///     }
+    ///     assert_eq!(seen, vec![(0, Ok(1)), (1, Ok(2))]);
+    ///     assert_eq!(s.finish(), Some(Ok([1, 2])));
+    /// })
+    /// ```
+    pub fn into_stream(self) -> TryJoinStream<Fut, N> {
+        CombinatorArrayStream::new(self)
+    }
+}
+
 impl<T, E, Fut, const N: usize> CombinatorBehaviorArray<Fut, N> for TryJoinBehavior
 where
     Fut: Future<Output = Result<T, E>>,
@@ -32,6 +79,12 @@ where
     fn when_completed_arr(arr: [Self::StoredItem; N]) -> Self::Output {
         Ok(arr)
     }
+
+    const AWAITS_ALL: bool = true;
+
+    fn ends_stream(output: &Fut::Output) -> bool {
+        output.is_err()
+    }
 }
 
 impl<T, E, Fut, const N: usize> TryJoinTrait for [Fut; N]
@@ -51,7 +104,7 @@ where
 mod test {
     use super::*;
     use std::future;
-    use std::io::{self, Error, ErrorKind};
+    use std::io::{self, Error};
 
     #[test]
     fn all_ok() {
@@ -66,11 +119,76 @@ mod test {
     #[test]
     fn one_err() {
         futures_lite::future::block_on(async {
-            let err = Error::new(ErrorKind::Other, "oh no");
+            let err = Error::other("oh no");
             let res: io::Result<_> = [future::ready(Ok("hello")), future::ready(Err(err))]
                 .try_join()
                 .await;
             assert_eq!(res.unwrap_err().to_string(), String::from("oh no"));
         });
     }
+
+    #[test]
+    fn into_stream_err() {
+        use futures_lite::StreamExt;
+
+        futures_lite::future::block_on(async {
+            let mut s = core::pin::pin!([
+                future::ready(Ok("hello")),
+                future::ready(Err("oh no")),
+                future::ready(Ok("world")),
+            ]
+            .try_join()
+            .into_stream());
+
+            // The stream stops at the first error.
+            assert_eq!(s.next().await, Some((0, Ok("hello"))));
+            assert_eq!(s.next().await, Some((1, Err("oh no"))));
+            assert_eq!(s.next().await, None);
+        });
+    }
+
+    #[test]
+    fn into_stream_finish_err() {
+        use futures_lite::StreamExt;
+
+        futures_lite::future::block_on(async {
+            let mut s = core::pin::pin!([
+                future::ready(Ok("hello")),
+                future::ready(Err("oh no")),
+                future::ready(Err("again")),
+            ]
+            .try_join()
+            .into_stream()
+            .keep_outputs());
+
+            assert_eq!(s.next().await, Some((0, Ok("hello"))));
+            assert_eq!(s.as_mut().finish(), None);
+            assert_eq!(s.next().await, Some((1, Err("oh no"))));
+            // The first error is what awaiting the try-join returns.
+            assert_eq!(s.as_mut().finish(), Some(Err("oh no")));
+        });
+    }
+
+    #[test]
+    fn into_stream_non_clone() {
+        use futures_lite::StreamExt;
+
+        futures_lite::future::block_on(async {
+            let mut s = core::pin::pin!([
+                future::ready(Ok::<_, io::Error>(Box::new(1))),
+                future::ready(Err(Error::other("oh no"))),
+            ]
+            .try_join()
+            .into_stream());
+
+            let (index, output) = s.next().await.unwrap();
+            assert_eq!((index, *output.unwrap()), (0, 1));
+            let (index, output) = s.next().await.unwrap();
+            assert_eq!(
+                (index, output.unwrap_err().to_string()),
+                (1, "oh no".to_string())
+            );
+            assert!(s.next().await.is_none());
+        });
+    }
 }
@@ -2,6 +2,7 @@ use core::future::Future;
 
 pub(crate) mod array;
 pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
 pub(crate) mod vec;
 
 /// Wait for all futures to complete successfully, or abort early on error.
@@ -9,8 +10,8 @@ pub(crate) mod vec;
 /// In the case a future errors, all other futures will be cancelled. If
 /// futures have been completed, their results will be discarded.
 ///
-/// If you want to keep partial data in the case of failure, see the `merge`
-/// operation.
+/// If you want to keep partial data in the case of failure, see the
+/// [`Settle`][super::Settle] operation.
 pub trait TryJoin {
     /// The resulting output type.
     type Ok;
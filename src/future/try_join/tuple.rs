@@ -12,6 +12,8 @@ impl<T, E> MapResult<Result<T, E>> for MapResultTryJoin {
     fn to_final_result(result: Result<T, E>) -> Self::FinalResult {
         result
     }
+
+    const AWAITS_ALL: bool = true;
 }
 
 macro_rules! impl_try_join_tuple {
@@ -53,7 +55,7 @@ mod test {
 
     use super::*;
     use std::future;
-    use std::io::{self, Error, ErrorKind};
+    use std::io::{self, Error};
 
     #[test]
     fn ok() {
@@ -71,7 +73,7 @@ mod test {
     #[test]
     fn err() {
         futures_lite::future::block_on(async {
-            let err = Error::new(ErrorKind::Other, "oh no");
+            let err = Error::other("oh no");
             let res = (
                 future::ready(io::Result::Ok("hello")),
                 future::ready(Result::<i32, _>::Err(err)),
@@ -1,8 +1,8 @@
-use super::super::common::{CombinatorBehaviorVec, CombinatorVec};
+use super::super::common::{CombinatorBehaviorVec, CombinatorVec, CombinatorVecStream};
 use super::{TryJoin as TryJoinTrait, TryJoinBehavior};
 
+use alloc::vec::Vec;
 use core::future::{Future, IntoFuture};
-use std::vec::Vec;
 
 /// Wait for all futures to complete successfully, or abort early on error.
 ///
@@ -13,6 +13,53 @@ use std::vec::Vec;
 /// [`TryJoin`]: crate::future::TryJoin
 pub type TryJoin<Fut> = CombinatorVec<Fut, TryJoinBehavior>;
 
+/// A stream which yields the output of each future as soon as it completes,
+/// up to and including the first error.
+///
+/// This `struct` is created by the `into_stream` method on [`TryJoin`]. See its
+/// documentation for more.
+pub type TryJoinStream<Fut> = CombinatorVecStream<Fut, TryJoinBehavior>;
+
+impl<T, E, Fut> TryJoin<Fut>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    /// Convert this future into a stream which yields the output of each
+    /// subfuture as soon as it completes, along with the subfuture's index.
+    ///
+    /// The stream ends once all subfutures have completed, or right after it
+    /// yields the first error, just like awaiting the combinator returns early
+    /// on it. Drop the stream to cancel the remaining subfutures.
+    ///
+    /// To also get the output the combinator would have resolved to, call
+    /// [`keep_outputs`][TryJoinStream::keep_outputs] on the stream and
+    /// [`finish`][TryJoinStream::finish] it once it's exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    /// use futures_lite::StreamExt;
+    /// use std::future;
+    /// use std::pin::pin;
+    ///
+    /// block_on(async {
+    ///     let mut s = pin!(vec![future::ready(Ok::<_, ()>(1)), future::ready(Ok(2))].try_join().into_stream().keep_outputs());
+    ///
+    ///     let mut seen = vec![];
+    ///     while let Some((index, output)) = s.next().await {
+    ///         seen.push((index, output));
+    ///     }
+    ///     assert_eq!(seen, vec![(0, Ok(1)), (1, Ok(2))]);
+    ///     assert_eq!(s.finish(), Some(Ok(vec![1, 2])));
+    /// })
+    /// ```
+    pub fn into_stream(self) -> TryJoinStream<Fut> {
+        CombinatorVecStream::new(self)
+    }
+}
+
 impl<T, E, Fut> CombinatorBehaviorVec<Fut> for TryJoinBehavior
 where
     Fut: Future<Output = Result<T, E>>,
@@ -34,6 +81,10 @@ where
     fn when_completed_vec(vec: Vec<Self::StoredItem>) -> Self::Output {
         Ok(vec)
     }
+
+    fn ends_stream(output: &Fut::Output) -> bool {
+        output.is_err()
+    }
 }
 
 impl<T, E, Fut> TryJoinTrait for Vec<Fut>
@@ -53,7 +104,7 @@ where
 mod test {
     use super::*;
     use std::future;
-    use std::io::{self, Error, ErrorKind};
+    use std::io::{self, Error};
 
     #[test]
     fn all_ok() {
@@ -68,11 +119,31 @@ mod test {
     #[test]
     fn one_err() {
         futures_lite::future::block_on(async {
-            let err = Error::new(ErrorKind::Other, "oh no");
+            let err = Error::other("oh no");
             let res: io::Result<_> = vec![future::ready(Ok("hello")), future::ready(Err(err))]
                 .try_join()
                 .await;
             assert_eq!(res.unwrap_err().to_string(), String::from("oh no"));
         });
     }
+
+    #[test]
+    fn into_stream_err() {
+        use futures_lite::StreamExt;
+
+        futures_lite::future::block_on(async {
+            let mut s = core::pin::pin!(vec![
+                future::ready(Ok("hello")),
+                future::ready(Err("oh no")),
+                future::ready(Ok("world")),
+            ]
+            .try_join()
+            .into_stream());
+
+            // The stream stops at the first error.
+            assert_eq!(s.next().await, Some((0, Ok("hello"))));
+            assert_eq!(s.next().await, Some((1, Err("oh no"))));
+            assert_eq!(s.next().await, None);
+        });
+    }
 }
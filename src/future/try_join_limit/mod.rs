@@ -0,0 +1,31 @@
+use core::future::Future;
+
+pub(crate) mod vec;
+
+/// Wait for all futures to complete successfully, or abort early on error,
+/// with a limit on how many futures are polled at once.
+///
+/// This is implemented for any iterator of futures, including vectors.
+pub trait TryJoinLimit {
+    /// The resulting output type.
+    type Ok;
+
+    /// The resulting error type.
+    type Error;
+
+    /// Which kind of future are we turning this into?
+    type Future: Future<Output = Result<Self::Ok, Self::Error>>;
+
+    /// Waits for multiple futures to complete successfully, keeping at most
+    /// `limit` futures in flight.
+    ///
+    /// Futures are started in iteration order. Once a future completes, the
+    /// next future is started in its place. The outputs are returned in
+    /// iteration order, once all futures complete. If a future fails, all
+    /// other futures are cancelled and the remaining futures are not started.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    fn try_join_limit(self, limit: usize) -> Self::Future;
+}
@@ -0,0 +1,68 @@
+use super::super::common::CombinatorLimit;
+use super::super::try_join::TryJoinBehavior;
+use super::TryJoinLimit as TryJoinLimitTrait;
+
+use alloc::vec::Vec;
+use core::future::IntoFuture;
+
+/// Wait for all futures to complete successfully, or abort early on error,
+/// with a limit on how many futures are polled at once.
+///
+/// This `struct` is created by the [`try_join_limit`] method on the
+/// [`TryJoinLimit`] trait. See its documentation for more.
+///
+/// [`try_join_limit`]: crate::future::TryJoinLimit::try_join_limit
+/// [`TryJoinLimit`]: crate::future::TryJoinLimit
+pub type TryJoinLimit<I> = CombinatorLimit<I, TryJoinBehavior>;
+
+impl<I, T, E> TryJoinLimitTrait for I
+where
+    I: IntoIterator,
+    I::Item: IntoFuture<Output = Result<T, E>>,
+{
+    type Ok = Vec<T>;
+    type Error = E;
+    type Future = TryJoinLimit<I::IntoIter>;
+
+    fn try_join_limit(self, limit: usize) -> Self::Future {
+        TryJoinLimit::new(self.into_iter(), limit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+    use std::future;
+    use std::io::{self, Error};
+
+    #[test]
+    fn all_ok() {
+        futures_lite::future::block_on(async {
+            let res: io::Result<_> = vec![future::ready(Ok("hello")), future::ready(Ok("world"))]
+                .try_join_limit(1)
+                .await;
+            assert_eq!(res.unwrap(), vec!["hello", "world"]);
+        })
+    }
+
+    #[test]
+    fn stops_starting_on_err() {
+        futures_lite::future::block_on(async {
+            let started = Cell::new(0);
+            let futs = (0..10).map(|i| {
+                let started = &started;
+                async move {
+                    started.set(started.get() + 1);
+                    match i {
+                        3 => Err(Error::other("oh no")),
+                        _ => Ok(i),
+                    }
+                }
+            });
+            let res = futs.try_join_limit(2).await;
+            assert_eq!(res.unwrap_err().to_string(), "oh no");
+            assert!(started.get() < 10);
+        });
+    }
+}
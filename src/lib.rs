@@ -30,6 +30,17 @@
 //! remove the need to think of "merge" as a verb, and would enable treating
 //! sets of futures concurrently.
 //!
+//! # Features
+//!
+//! - `std` (default): implements `std::error::Error` for the error types.
+//!   Implies `alloc`.
+//! - `alloc`: enables the implementations for `Vec`, the growable groups,
+//!   and the operations which limit or stagger concurrency.
+//!
+//! Without `alloc` the crate is `#![no_std]`, and the operations on arrays
+//! and tuples are still available. They don't track which subfutures were
+//! woken however, so every subfuture is polled whenever any of them wakes.
+//!
 //! # Examples
 //!
 //! Concurrently await multiple heterogenous futures:
@@ -46,22 +57,41 @@
 //! })
 //! ```
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![deny(missing_debug_implementations, nonstandard_style)]
 #![warn(missing_docs, unreachable_pub)]
 #![allow(non_snake_case)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod utils;
 
 /// The futures concurrency prelude.
 pub mod prelude {
     pub use super::future::Join as _;
+    #[cfg(feature = "alloc")]
+    pub use super::future::JoinLimit as _;
     pub use super::future::Race as _;
     pub use super::future::RaceOk as _;
+    pub use super::future::RaceOkStaggered as _;
+    pub use super::future::Settle as _;
     pub use super::future::TryJoin as _;
+    #[cfg(feature = "alloc")]
+    pub use super::future::TryJoinLimit as _;
     pub use super::stream::Chain as _;
+    pub use super::stream::ChainIndexed as _;
+    pub use super::stream::CombineLatest as _;
+    pub use super::stream::Interleave as _;
     pub use super::stream::IntoStream as _;
     pub use super::stream::Merge as _;
+    pub use super::stream::MergeBiased as _;
+    pub use super::stream::MergeIndexed as _;
+    #[cfg(feature = "alloc")]
+    pub use super::stream::MergeLimit as _;
+    pub use super::stream::MergeWeighted as _;
     pub use super::stream::Zip as _;
+    pub use super::stream::ZipLongest as _;
 }
 
 pub mod future;
@@ -69,22 +99,43 @@ pub mod stream;
 
 /// Helper functions and types for fixed-length arrays.
 pub mod array {
-    pub use crate::future::join::array::Join;
+    pub use crate::future::join::array::{Join, JoinStream};
     pub use crate::future::race::array::Race;
     pub use crate::future::race_ok::array::RaceOk;
-    pub use crate::future::try_join::array::TryJoin;
+    pub use crate::future::race_ok_staggered::array::RaceOkStaggered;
+    pub use crate::future::settle::array::Settle;
+    pub use crate::future::try_join::array::{TryJoin, TryJoinStream};
     pub use crate::stream::chain::array::Chain;
+    pub use crate::stream::chain_indexed::array::ChainIndexed;
+    pub use crate::stream::combine_latest::array::CombineLatest;
+    pub use crate::stream::interleave::array::Interleave;
     pub use crate::stream::merge::array::Merge;
+    pub use crate::stream::merge_biased::array::MergeBiased;
+    pub use crate::stream::merge_indexed::array::MergeIndexed;
+    pub use crate::stream::merge_weighted::array::MergeWeighted;
     pub use crate::stream::zip::array::Zip;
+    pub use crate::stream::zip_longest::array::ZipLongest;
 }
 
 /// A contiguous growable array type with heap-allocated contents, written `Vec<T>`.
+#[cfg(feature = "alloc")]
 pub mod vec {
-    pub use crate::future::join::vec::Join;
+    pub use crate::future::join::vec::{Join, JoinStream};
+    pub use crate::future::join_limit::vec::JoinLimit;
     pub use crate::future::race::vec::Race;
     pub use crate::future::race_ok::vec::RaceOk;
-    pub use crate::future::try_join::vec::TryJoin;
+    pub use crate::future::race_ok_staggered::vec::RaceOkStaggered;
+    pub use crate::future::settle::vec::Settle;
+    pub use crate::future::try_join::vec::{TryJoin, TryJoinStream};
+    pub use crate::future::try_join_limit::vec::TryJoinLimit;
     pub use crate::stream::chain::vec::Chain;
+    pub use crate::stream::chain_indexed::vec::ChainIndexed;
+    pub use crate::stream::combine_latest::vec::CombineLatest;
+    pub use crate::stream::interleave::vec::Interleave;
     pub use crate::stream::merge::vec::Merge;
+    pub use crate::stream::merge_biased::vec::MergeBiased;
+    pub use crate::stream::merge_indexed::vec::MergeIndexed;
+    pub use crate::stream::merge_weighted::vec::MergeWeighted;
     pub use crate::stream::zip::vec::Zip;
+    pub use crate::stream::zip_longest::vec::ZipLongest;
 }
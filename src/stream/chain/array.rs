@@ -2,7 +2,7 @@ use core::fmt;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use pin_project::pin_project;
 
 use crate::utils;
@@ -51,6 +51,16 @@ impl<S: Stream, const N: usize> Stream for Chain<S, N> {
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        utils::size_hint::sum(self.streams[self.index..].iter().map(Stream::size_hint))
+    }
+}
+
+impl<S: Stream, const N: usize> FusedStream for Chain<S, N> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
 }
 
 impl<S, const N: usize> fmt::Debug for Chain<S, N>
@@ -98,4 +108,20 @@ mod tests {
             assert_eq!(s.next().await, None);
         })
     }
+
+    #[test]
+    fn size_hint() {
+        block_on(async {
+            let a = stream::iter(vec![1, 2]);
+            let b = stream::iter(vec![3]);
+            let mut s = [a, b].chain();
+            assert_eq!(s.size_hint(), (3, Some(3)));
+
+            s.next().await;
+            s.next().await;
+            assert_eq!(s.size_hint(), (1, Some(1)));
+            s.next().await;
+            assert_eq!(s.size_hint(), (0, Some(0)));
+        })
+    }
 }
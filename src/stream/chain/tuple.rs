@@ -1 +1,157 @@
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
 
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+use crate::stream::IntoStream;
+use crate::utils;
+
+use super::Chain as ChainTrait;
+
+macro_rules! impl_chain_tuple {
+    ($mod_name:ident $StructName:ident $($F:ident=$fut_idx:tt)+) => {
+        mod $mod_name {
+            #[pin_project::pin_project]
+            pub(super) struct Streams<$($F,)+> { $(#[pin] pub(super) $F: $F),+ }
+        }
+
+        /// A stream that chains multiple streams one after another.
+        ///
+        /// This `struct` is created by the [`chain`] method on the [`Chain`] trait. See its
+        /// documentation for more.
+        ///
+        /// [`chain`]: crate::stream::Chain::chain
+        /// [`Chain`]: crate::stream::Chain
+        #[pin_project]
+        pub struct $StructName<$($F),*> {
+            #[pin] streams: $mod_name::Streams<$($F,)+>,
+            index: usize,
+            done: bool,
+        }
+
+        impl<T, $($F),*> Stream for $StructName<$($F),*>
+        where $(
+            $F: Stream<Item = T>,
+        )* {
+            type Item = T;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let this = self.project();
+
+                assert!(!*this.done, "Stream should not be polled after completion");
+
+                let mut streams = this.streams.project();
+                loop {
+                    let poll_res = match *this.index {
+                        $(
+                            $fut_idx => streams.$F.as_mut().poll_next(cx),
+                        )+
+                        _ => {
+                            *this.done = true;
+                            return Poll::Ready(None);
+                        }
+                    };
+                    match poll_res {
+                        Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                        Poll::Ready(None) => {
+                            *this.index += 1;
+                            continue;
+                        }
+                        Poll::Pending => return Poll::Pending,
+                    }
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let hints = [$(self.streams.$F.size_hint()),+];
+                utils::size_hint::sum(hints.into_iter().skip(self.index))
+            }
+        }
+
+        impl<T, $($F),*> FusedStream for $StructName<$($F),*>
+        where $(
+            $F: Stream<Item = T>,
+        )* {
+            fn is_terminated(&self) -> bool {
+                self.done
+            }
+        }
+
+        impl<$($F),*> fmt::Debug for $StructName<$($F),*>
+        where $(
+            $F: fmt::Debug,
+        )* {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("Chain")
+                    $( .field(&self.streams.$F) )*
+                    .finish()
+            }
+        }
+
+        impl<T, $($F),*> ChainTrait for ($($F,)*)
+        where $(
+            $F: IntoStream<Item = T>,
+        )* {
+            type Item = T;
+            type Stream = $StructName<$($F::IntoStream),*>;
+
+            fn chain(self) -> Self::Stream {
+                let ($($F,)*): ($($F,)*) = self;
+                $StructName {
+                    streams: $mod_name::Streams { $($F: $F.into_stream()),+ },
+                    index: 0,
+                    done: false,
+                }
+            }
+        }
+    };
+}
+
+impl_chain_tuple! { chain1 Chain1 A=0 }
+impl_chain_tuple! { chain2 Chain2 A=0 B=1 }
+impl_chain_tuple! { chain3 Chain3 A=0 B=1 C=2 }
+impl_chain_tuple! { chain4 Chain4 A=0 B=1 C=2 D=3 }
+impl_chain_tuple! { chain5 Chain5 A=0 B=1 C=2 D=3 E=4 }
+impl_chain_tuple! { chain6 Chain6 A=0 B=1 C=2 D=3 E=4 F=5 }
+impl_chain_tuple! { chain7 Chain7 A=0 B=1 C=2 D=3 E=4 F=5 G=6 }
+impl_chain_tuple! { chain8 Chain8 A=0 B=1 C=2 D=3 E=4 F=5 G=6 H=7 }
+impl_chain_tuple! { chain9 Chain9 A=0 B=1 C=2 D=3 E=4 F=5 G=6 H=7 I=8 }
+impl_chain_tuple! { chain10 Chain10 A=0 B=1 C=2 D=3 E=4 F=5 G=6 H=7 I=8 J=9 }
+impl_chain_tuple! { chain11 Chain11 A=0 B=1 C=2 D=3 E=4 F=5 G=6 H=7 I=8 J=9 K=10 }
+impl_chain_tuple! { chain12 Chain12 A=0 B=1 C=2 D=3 E=4 F=5 G=6 H=7 I=8 J=9 K=10 L=11 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn chain_tuple_3() {
+        block_on(async {
+            let a = stream::once(1);
+            let b = stream::iter(vec![2, 3]);
+            let c = stream::once(4);
+            let s = (a, b, c).chain();
+
+            let items: Vec<_> = s.collect().await;
+            assert_eq!(items, [1, 2, 3, 4]);
+        })
+    }
+
+    #[test]
+    fn size_hint() {
+        block_on(async {
+            let a = stream::iter(vec![1, 2]);
+            let b = stream::once(3);
+            let mut s = (a, b).chain();
+            assert_eq!(s.size_hint(), (3, Some(3)));
+
+            s.next().await;
+            assert_eq!(s.size_hint(), (2, Some(2)));
+        })
+    }
+}
@@ -1,8 +1,9 @@
+use alloc::vec::Vec;
 use core::fmt;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use pin_project::pin_project;
 
 use crate::utils;
@@ -51,6 +52,16 @@ impl<S: Stream> Stream for Chain<S> {
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        utils::size_hint::sum(self.streams[self.index..].iter().map(Stream::size_hint))
+    }
+}
+
+impl<S: Stream> FusedStream for Chain<S> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
 }
 
 impl<S> fmt::Debug for Chain<S>
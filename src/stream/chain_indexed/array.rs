@@ -0,0 +1,53 @@
+use super::ChainIndexed as ChainIndexedTrait;
+use crate::stream::chain::array::Chain;
+use crate::stream::merge_indexed::Indexed;
+use crate::stream::{Chain as ChainTrait, IntoStream};
+
+/// A stream that chains multiple streams one after another, tagging each item
+/// with the index of its stream.
+///
+/// This `struct` is created by the [`chain_indexed`] method on the
+/// [`ChainIndexed`] trait. See its documentation for more.
+///
+/// [`chain_indexed`]: crate::stream::ChainIndexed::chain_indexed
+/// [`ChainIndexed`]: crate::stream::ChainIndexed
+pub type ChainIndexed<S, const N: usize> = Chain<Indexed<S>, N>;
+
+impl<S, const N: usize> ChainIndexedTrait for [S; N]
+where
+    S: IntoStream,
+{
+    type Item = (usize, S::Item);
+    type Stream = ChainIndexed<S::IntoStream, N>;
+
+    fn chain_indexed(self) -> Self::Stream {
+        let mut index = 0;
+        self.map(|s| {
+            let indexed = Indexed::new(s.into_stream(), index);
+            index += 1;
+            indexed
+        })
+        .chain()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn chain_indexed_array_3() {
+        block_on(async {
+            let a = stream::once('a');
+            let b = stream::empty();
+            let c = stream::iter(vec!['c', 'd']);
+            let s = [a.boxed(), b.boxed(), c.boxed()].chain_indexed();
+
+            let items: Vec<_> = s.collect().await;
+            assert_eq!(items, [(0, 'a'), (2, 'c'), (2, 'd')]);
+        })
+    }
+}
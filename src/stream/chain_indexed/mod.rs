@@ -0,0 +1,48 @@
+use futures_core::Stream;
+
+pub(crate) mod array;
+pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Takes multiple streams and creates a new stream over all in sequence,
+/// tagging each item with the stream it came from.
+///
+/// This works like [`Chain`][super::Chain], except that every item is
+/// annotated with the position of the stream that produced it. For arrays and
+/// vectors the item is paired with the index of the stream. For tuples the
+/// item is wrapped in one of the [`select_types`] enums, which means the
+/// streams don't need to share the same item type.
+///
+/// [`select_types`]: crate::future::select_types
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_concurrency::future::select_types::SelectedFrom2;
+/// use futures_lite::stream::{self, StreamExt};
+/// use futures_lite::future::block_on;
+///
+/// block_on(async {
+///     let header = stream::once("header");
+///     let rows = stream::iter(vec![1, 2]);
+///     let mut s = (header, rows).chain_indexed();
+///
+///     assert_eq!(s.next().await, Some(SelectedFrom2::A0("header")));
+///     assert_eq!(s.next().await, Some(SelectedFrom2::A1(1)));
+///     assert_eq!(s.next().await, Some(SelectedFrom2::A1(2)));
+///     assert_eq!(s.next().await, None);
+/// })
+/// ```
+pub trait ChainIndexed {
+    /// The resulting output type.
+    type Item;
+
+    /// The stream type.
+    type Stream: Stream<Item = Self::Item>;
+
+    /// Combine multiple streams into a single stream in sequence, tagging
+    /// each item with the stream it came from.
+    fn chain_indexed(self) -> Self::Stream;
+}
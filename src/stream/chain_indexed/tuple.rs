@@ -0,0 +1,63 @@
+use super::ChainIndexed as ChainIndexedTrait;
+use crate::future::select_types;
+use crate::stream::merge_indexed::tuple::Select;
+use crate::stream::{Chain as ChainTrait, IntoStream};
+
+macro_rules! impl_chain_indexed_tuple {
+    ($SelectedFrom:ident $($F:ident)+) => {
+        impl<$($F),+> ChainIndexedTrait for ($($F,)+)
+        where $(
+            $F: IntoStream,
+        )+ {
+            type Item = select_types::$SelectedFrom<$($F::Item),+>;
+            type Stream = <($(Select<$F::IntoStream, Self::Item>,)+) as ChainTrait>::Stream;
+
+            fn chain_indexed(self) -> Self::Stream {
+                let ($($F,)+) = self;
+                (
+                    $(Select::new($F.into_stream(), select_types::$SelectedFrom::$F),)+
+                ).chain()
+            }
+        }
+    };
+}
+
+impl_chain_indexed_tuple! { SelectedFrom1 A0 }
+impl_chain_indexed_tuple! { SelectedFrom2 A0 A1 }
+impl_chain_indexed_tuple! { SelectedFrom3 A0 A1 A2 }
+impl_chain_indexed_tuple! { SelectedFrom4 A0 A1 A2 A3 }
+impl_chain_indexed_tuple! { SelectedFrom5 A0 A1 A2 A3 A4 }
+impl_chain_indexed_tuple! { SelectedFrom6 A0 A1 A2 A3 A4 A5 }
+impl_chain_indexed_tuple! { SelectedFrom7 A0 A1 A2 A3 A4 A5 A6 }
+impl_chain_indexed_tuple! { SelectedFrom8 A0 A1 A2 A3 A4 A5 A6 A7 }
+impl_chain_indexed_tuple! { SelectedFrom9 A0 A1 A2 A3 A4 A5 A6 A7 A8 }
+impl_chain_indexed_tuple! { SelectedFrom10 A0 A1 A2 A3 A4 A5 A6 A7 A8 A9 }
+impl_chain_indexed_tuple! { SelectedFrom11 A0 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 }
+impl_chain_indexed_tuple! { SelectedFrom12 A0 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn chain_indexed_tuple_3() {
+        block_on(async {
+            let a = stream::iter(vec![1, 2]);
+            let b = stream::once("hello");
+            let c = stream::once('c');
+            let mut s = (a, b, c).chain_indexed();
+
+            assert_eq!(s.next().await, Some(select_types::SelectedFrom3::A0(1)));
+            assert_eq!(s.next().await, Some(select_types::SelectedFrom3::A0(2)));
+            assert_eq!(
+                s.next().await,
+                Some(select_types::SelectedFrom3::A1("hello"))
+            );
+            assert_eq!(s.next().await, Some(select_types::SelectedFrom3::A2('c')));
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
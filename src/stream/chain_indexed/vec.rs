@@ -0,0 +1,53 @@
+use super::ChainIndexed as ChainIndexedTrait;
+use crate::stream::chain::vec::Chain;
+use crate::stream::merge_indexed::Indexed;
+use crate::stream::{Chain as ChainTrait, IntoStream};
+
+use alloc::vec::Vec;
+
+/// A stream that chains multiple streams one after another, tagging each item
+/// with the index of its stream.
+///
+/// This `struct` is created by the [`chain_indexed`] method on the
+/// [`ChainIndexed`] trait. See its documentation for more.
+///
+/// [`chain_indexed`]: crate::stream::ChainIndexed::chain_indexed
+/// [`ChainIndexed`]: crate::stream::ChainIndexed
+pub type ChainIndexed<S> = Chain<Indexed<S>>;
+
+impl<S> ChainIndexedTrait for Vec<S>
+where
+    S: IntoStream,
+{
+    type Item = (usize, S::Item);
+    type Stream = ChainIndexed<S::IntoStream>;
+
+    fn chain_indexed(self) -> Self::Stream {
+        self.into_iter()
+            .enumerate()
+            .map(|(index, s)| Indexed::new(s.into_stream(), index))
+            .collect::<Vec<_>>()
+            .chain()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn chain_indexed_vec_3() {
+        block_on(async {
+            let a = stream::once(1);
+            let b = stream::once(2);
+            let c = stream::once(3);
+            let s = vec![a, b, c].chain_indexed();
+
+            let items: Vec<_> = s.collect().await;
+            assert_eq!(items, [(0, 1), (1, 2), (2, 3)]);
+        })
+    }
+}
@@ -0,0 +1,78 @@
+use super::{CombineLatest as CombineLatestTrait, CombineLatestBehavior};
+use crate::stream::common::{Fixed, SlottedStreams, StreamCombinatorArray};
+use crate::stream::IntoStream;
+
+use futures_core::Stream;
+
+/// A stream that combines multiple streams into a single stream of their
+/// latest items.
+///
+/// This `struct` is created by the [`combine_latest`] method on the
+/// [`CombineLatest`] trait. See its documentation for more.
+///
+/// [`combine_latest`]: crate::stream::CombineLatest::combine_latest
+/// [`CombineLatest`]: crate::stream::CombineLatest
+pub type CombineLatest<S, const N: usize> = StreamCombinatorArray<
+    [S; N],
+    CombineLatestBehavior<Fixed<N>, [Option<<S as Stream>::Item>; N]>,
+    N,
+>;
+
+impl<S, const N: usize> CombineLatestTrait for [S; N]
+where
+    S: IntoStream,
+    S::Item: Clone,
+{
+    type Item = [S::Item; N];
+    type Stream = CombineLatest<S::IntoStream, N>;
+
+    fn combine_latest(self) -> Self::Stream {
+        let streams = self.map(|i| i.into_stream());
+        let latest = streams.empty_slots();
+        StreamCombinatorArray::new(streams, CombineLatestBehavior::new(N, latest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::channel::local_channel;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    /// This test case uses channels so we'll have streams that return Pending from time to time.
+    #[test]
+    fn combine_latest_channels() {
+        block_on(async {
+            let (send_a, a) = local_channel();
+            let (send_b, b) = local_channel();
+            let mut s = [a, b].combine_latest();
+
+            send_a.send(1);
+            send_a.send(2);
+            send_b.send(10);
+            assert_eq!(s.next().await, Some([1, 10]));
+            assert_eq!(s.next().await, Some([2, 10]));
+
+            send_b.send(20);
+            assert_eq!(s.next().await, Some([2, 20]));
+            drop(send_b);
+            send_a.send(3);
+            assert_eq!(s.next().await, Some([3, 20]));
+
+            drop(send_a);
+            assert_eq!(s.next().await, None);
+        })
+    }
+
+    #[test]
+    fn ends_early_without_item() {
+        block_on(async {
+            let a = stream::repeat(1).take(usize::MAX);
+            let b = stream::empty();
+            let mut s = [a.boxed_local(), b.boxed_local()].combine_latest();
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
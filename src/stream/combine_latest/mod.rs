@@ -0,0 +1,154 @@
+use crate::stream::common::{IndexQueue, Slots, SlottedStreams, Step, Storage, StreamBehavior};
+use crate::utils::PollState;
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+
+pub(crate) mod array;
+pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Combines multiple streams into a single stream of their latest items.
+///
+/// Once every stream has yielded an item, the combined stream yields the
+/// latest item of every stream, and then yields again whenever any stream
+/// yields a new item. Items are cloned, since the latest item of each stream
+/// is kept around to be part of the next combination too.
+///
+/// The stream ends once every stream has ended, or as soon as a stream ends
+/// without ever having yielded an item.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_lite::stream::{self, StreamExt};
+/// use futures_lite::future::block_on;
+///
+/// block_on(async {
+///     let width = stream::once(80);
+///     let theme = stream::iter(vec!["light", "dark"]);
+///     let s = (width, theme).combine_latest();
+///
+///     let states: Vec<_> = s.collect().await;
+///     assert_eq!(states, vec![(80, "light"), (80, "dark")]);
+/// })
+/// ```
+pub trait CombineLatest {
+    /// What's the return type of our stream?
+    type Item;
+
+    /// What stream do we return?
+    type Stream: Stream<Item = Self::Item>;
+
+    /// Combine multiple streams into a single stream of their latest items.
+    fn combine_latest(self) -> Self::Stream;
+}
+
+/// The behavior of [`CombineLatest`] streams: keep the latest item of every
+/// substream, and yield all of them whenever one changes.
+pub struct CombineLatestBehavior<K: Storage, L> {
+    /// The states of the substreams.
+    /// Pending = stream is sleeping
+    /// Ready = stream is awake
+    /// Consumed = stream is complete
+    state: K::Buf<PollState>,
+    /// The awake substreams.
+    queue: IndexQueue<K>,
+    /// The latest item of each substream.
+    latest: L,
+    /// Whether each substream has yielded an item yet.
+    seen: K::Buf<bool>,
+    /// Number of substreams which haven't yielded an item yet.
+    missing: usize,
+    /// Number of substreams that haven't completed.
+    pending: usize,
+}
+
+impl<K: Storage, L> CombineLatestBehavior<K, L> {
+    pub(crate) fn new(len: usize, latest: L) -> Self {
+        Self {
+            // The wakers start out awake, so every substream is queued on the
+            // first poll.
+            state: K::buf(len, |_| PollState::Pending),
+            queue: IndexQueue::new(len),
+            latest,
+            seen: K::buf(len, |_| false),
+            missing: len,
+            pending: len,
+        }
+    }
+}
+
+impl<K: Storage, L> fmt::Debug for CombineLatestBehavior<K, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CombineLatestBehavior")
+            .field("state", &self.state.as_ref())
+            .field("seen", &self.seen.as_ref())
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+impl<S, K, L> StreamBehavior<S> for CombineLatestBehavior<K, L>
+where
+    S: SlottedStreams<Slots = L> + ?Sized,
+    K: Storage,
+    L: Slots + Clone,
+{
+    type Item = L::Items;
+
+    fn wake(&mut self, idx: usize) {
+        let state = &mut self.state.as_mut()[idx];
+        if *state == PollState::Pending {
+            *state = PollState::Ready;
+            self.queue.push_back(idx);
+        }
+    }
+
+    fn next(&mut self) -> Option<usize> {
+        self.queue.pop_front()
+    }
+
+    fn poll(&mut self, streams: Pin<&mut S>, idx: usize, cx: &mut Context<'_>) -> Step<L::Items> {
+        match streams.poll_next_into(idx, cx, &mut self.latest) {
+            Poll::Ready(Some(())) => {
+                // Wake the substream to be polled again, it may have more
+                // items.
+                cx.waker().wake_by_ref();
+                self.state.as_mut()[idx] = PollState::Pending;
+
+                let seen = &mut self.seen.as_mut()[idx];
+                if !*seen {
+                    *seen = true;
+                    self.missing -= 1;
+                }
+                if self.missing == 0 {
+                    // Every slot is filled once nothing is missing.
+                    return Step::Yield(self.latest.clone_items());
+                }
+            }
+            Poll::Ready(None) => {
+                self.pending -= 1;
+                self.state.as_mut()[idx] = PollState::Consumed;
+                if !self.seen.as_ref()[idx] {
+                    // Without an item from this substream, nothing can be
+                    // yielded anymore.
+                    return Step::End;
+                }
+            }
+            Poll::Pending => {
+                self.state.as_mut()[idx] = PollState::Pending;
+            }
+        }
+        Step::Continue
+    }
+
+    fn is_done(&self) -> bool {
+        self.pending == 0
+    }
+}
@@ -0,0 +1,65 @@
+use super::{CombineLatest as CombineLatestTrait, CombineLatestBehavior};
+use crate::stream::common::*;
+use crate::stream::IntoStream;
+
+use futures_core::Stream;
+
+macro_rules! impl_combine_latest_tuple {
+    ($StructName:ident $Streams:ident $len:literal; $($F:ident)+) => {
+        pub(crate) type $StructName<$($F,)+> = StreamCombinatorArray<
+            $Streams<$($F,)+>,
+            CombineLatestBehavior<Fixed<$len>, ($(Option<<$F as Stream>::Item>,)+)>,
+            $len,
+        >;
+
+        impl<$($F),*> CombineLatestTrait for ($($F,)*)
+        where $(
+            $F: IntoStream,
+            $F::Item: Clone,
+        )* {
+            type Item = ($($F::Item,)+);
+            type Stream = $StructName<$($F::IntoStream,)+>;
+
+            fn combine_latest(self) -> Self::Stream {
+                let ($($F,)*): ($($F,)*) = self;
+                let streams = $Streams::new(($($F.into_stream(),)+));
+                let latest = streams.empty_slots();
+                StreamCombinatorArray::new(streams, CombineLatestBehavior::new($len, latest))
+            }
+        }
+    };
+}
+
+impl_combine_latest_tuple! { CombineLatest1 Streams1 1; A }
+impl_combine_latest_tuple! { CombineLatest2 Streams2 2; A B }
+impl_combine_latest_tuple! { CombineLatest3 Streams3 3; A B C }
+impl_combine_latest_tuple! { CombineLatest4 Streams4 4; A B C D }
+impl_combine_latest_tuple! { CombineLatest5 Streams5 5; A B C D E }
+impl_combine_latest_tuple! { CombineLatest6 Streams6 6; A B C D E F }
+impl_combine_latest_tuple! { CombineLatest7 Streams7 7; A B C D E F G }
+impl_combine_latest_tuple! { CombineLatest8 Streams8 8; A B C D E F G H }
+impl_combine_latest_tuple! { CombineLatest9 Streams9 9; A B C D E F G H I }
+impl_combine_latest_tuple! { CombineLatest10 Streams10 10; A B C D E F G H I J }
+impl_combine_latest_tuple! { CombineLatest11 Streams11 11; A B C D E F G H I J K }
+impl_combine_latest_tuple! { CombineLatest12 Streams12 12; A B C D E F G H I J K L }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::{future::block_on, stream, StreamExt};
+
+    #[test]
+    fn combine_latest_tuple_3() {
+        block_on(async {
+            let mut s = (
+                stream::iter([1, 2]),
+                stream::once("hello"),
+                stream::once('a'),
+            )
+                .combine_latest();
+            assert_eq!(s.next().await, Some((1, "hello", 'a')));
+            assert_eq!(s.next().await, Some((2, "hello", 'a')));
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
@@ -0,0 +1,75 @@
+use super::{CombineLatest as CombineLatestTrait, CombineLatestBehavior};
+use crate::stream::common::{Dynamic, SlottedStreams, StreamCombinatorVec};
+use crate::stream::IntoStream;
+
+use alloc::vec::Vec;
+
+use futures_core::Stream;
+
+/// A stream that combines multiple streams into a single stream of their
+/// latest items.
+///
+/// This `struct` is created by the [`combine_latest`] method on the
+/// [`CombineLatest`] trait. See its documentation for more.
+///
+/// [`combine_latest`]: crate::stream::CombineLatest::combine_latest
+/// [`CombineLatest`]: crate::stream::CombineLatest
+pub type CombineLatest<S> =
+    StreamCombinatorVec<S, CombineLatestBehavior<Dynamic, Vec<Option<<S as Stream>::Item>>>>;
+
+impl<S> CombineLatestTrait for Vec<S>
+where
+    S: IntoStream,
+    S::Item: Clone,
+{
+    type Item = Vec<S::Item>;
+    type Stream = CombineLatest<S::IntoStream>;
+
+    fn combine_latest(self) -> Self::Stream {
+        let streams: Vec<_> = self.into_iter().map(|i| i.into_stream()).collect();
+        let latest = streams.empty_slots();
+        let len = streams.len();
+        StreamCombinatorVec::new(streams, CombineLatestBehavior::new(len, latest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::channel::local_channel;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+
+    #[test]
+    fn combine_latest_channels() {
+        block_on(async {
+            let (send_a, a) = local_channel();
+            let (send_b, b) = local_channel();
+            let (send_c, c) = local_channel();
+            let mut s = vec![a, b, c].combine_latest();
+
+            send_a.send(1);
+            send_b.send(2);
+            send_c.send(3);
+            assert_eq!(s.next().await, Some(vec![1, 2, 3]));
+
+            send_b.send(20);
+            assert_eq!(s.next().await, Some(vec![1, 20, 3]));
+            drop(send_a);
+            drop(send_b);
+            send_c.send(30);
+            assert_eq!(s.next().await, Some(vec![1, 20, 30]));
+
+            drop(send_c);
+            assert_eq!(s.next().await, None);
+        })
+    }
+
+    #[test]
+    fn empty() {
+        block_on(async {
+            let mut s = Vec::<futures_lite::stream::Empty<u8>>::new().combine_latest();
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
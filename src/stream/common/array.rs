@@ -0,0 +1,147 @@
+use super::{IndexedStreams, Slots, SlottedStreams, Step, StreamBehavior};
+use crate::utils::{self, WakerArray};
+
+use core::array;
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+/// A stream combinator over a fixed number of substreams, which are stored in
+/// an array or a tuple.
+///
+/// The combinator hands out a waker per substream, and leaves it to the
+/// behavior `B` to pick which substreams to poll and what to yield.
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct StreamCombinatorArray<S, B, const N: usize> {
+    #[pin]
+    streams: S,
+    wakers: WakerArray<N>,
+    behavior: B,
+    /// Streams should not be polled after complete.
+    /// Tracked so the stream can report it is terminated, and panic when
+    /// polled again.
+    done: bool,
+}
+
+impl<S, B, const N: usize> StreamCombinatorArray<S, B, N> {
+    pub(crate) fn new(streams: S, behavior: B) -> Self {
+        Self {
+            streams,
+            wakers: WakerArray::new(),
+            behavior,
+            done: false,
+        }
+    }
+}
+
+impl<S, B, const N: usize> fmt::Debug for StreamCombinatorArray<S, B, N>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.streams.fmt(f)
+    }
+}
+
+impl<S, B, const N: usize> Stream for StreamCombinatorArray<S, B, N>
+where
+    B: StreamBehavior<S>,
+{
+    type Item = B::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        assert!(!*this.done, "Stream should not be polled after completing");
+
+        {
+            let mut awakeness = this.wakers.awakeness();
+            awakeness.set_parent_waker(cx.waker());
+            for &idx in awakeness.awake_list() {
+                this.behavior.wake(idx);
+            }
+            awakeness.clear();
+        }
+
+        while let Some(idx) = this.behavior.next() {
+            let mut cx = Context::from_waker(this.wakers.get(idx).unwrap());
+            match this.behavior.poll(this.streams.as_mut(), idx, &mut cx) {
+                Step::Yield(item) => return Poll::Ready(Some(item)),
+                Step::Continue => {}
+                Step::Wait => return Poll::Pending,
+                Step::End => {
+                    *this.done = true;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+
+        if this.behavior.is_done() {
+            *this.done = true;
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<S, B, const N: usize> FusedStream for StreamCombinatorArray<S, B, N>
+where
+    B: StreamBehavior<S>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<S, const N: usize> IndexedStreams for [S; N]
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next_at(
+        self: Pin<&mut Self>,
+        idx: usize,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        utils::get_pin_mut(self, idx).unwrap().poll_next(cx)
+    }
+}
+
+impl<S, const N: usize> SlottedStreams for [S; N]
+where
+    S: Stream,
+{
+    type Slots = [Option<S::Item>; N];
+
+    fn empty_slots(&self) -> Self::Slots {
+        array::from_fn(|_| None)
+    }
+
+    fn poll_next_into(
+        self: Pin<&mut Self>,
+        idx: usize,
+        cx: &mut Context<'_>,
+        slots: &mut Self::Slots,
+    ) -> Poll<Option<()>> {
+        self.poll_next_at(idx, cx)
+            .map(|item| item.map(|item| slots[idx] = Some(item)))
+    }
+}
+
+impl<T, const N: usize> Slots for [Option<T>; N] {
+    type Items = [T; N];
+
+    fn clone_items(&self) -> Self::Items
+    where
+        Self: Clone,
+    {
+        self.clone()
+            .map(|item| item.expect("every slot must be filled"))
+    }
+}
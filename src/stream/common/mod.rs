@@ -0,0 +1,166 @@
+mod array;
+mod tuple;
+#[cfg(feature = "alloc")]
+mod vec;
+
+pub(crate) use array::StreamCombinatorArray;
+pub(crate) use tuple::*;
+#[cfg(feature = "alloc")]
+pub(crate) use vec::StreamCombinatorVec;
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// A set of streams with the same item type, which can be polled by index.
+pub trait IndexedStreams {
+    /// The item of the substreams.
+    type Item;
+
+    /// Poll the substream at `idx`.
+    fn poll_next_at(
+        self: Pin<&mut Self>,
+        idx: usize,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>>;
+}
+
+/// A set of streams which can be polled by index, storing each item in the
+/// slot of its substream. Unlike [`IndexedStreams`], the substreams may have
+/// different item types.
+pub trait SlottedStreams {
+    /// One optional item for every substream.
+    type Slots: Slots;
+
+    /// Create a set of slots which are all empty.
+    fn empty_slots(&self) -> Self::Slots;
+
+    /// Poll the substream at `idx`, storing its item in `slots`.
+    fn poll_next_into(
+        self: Pin<&mut Self>,
+        idx: usize,
+        cx: &mut Context<'_>,
+        slots: &mut Self::Slots,
+    ) -> Poll<Option<()>>;
+}
+
+/// One optional item for every substream, such as `[Option<T>; N]`.
+pub trait Slots: Sized {
+    /// The items of all substreams, such as `[T; N]`.
+    type Items;
+
+    /// Clone the items out of the slots. Every slot must be filled.
+    fn clone_items(&self) -> Self::Items
+    where
+        Self: Clone;
+}
+
+/// How the per-substream state of a [`StreamBehavior`] is stored.
+pub trait Storage {
+    /// A buffer holding a value for every substream.
+    type Buf<T>: AsRef<[T]> + AsMut<[T]>;
+
+    /// Create a buffer for `len` substreams.
+    fn buf<T>(len: usize, f: impl FnMut(usize) -> T) -> Self::Buf<T>;
+}
+
+/// Storage for a fixed number of substreams, as in arrays and tuples.
+#[derive(Debug)]
+pub struct Fixed<const N: usize>;
+
+impl<const N: usize> Storage for Fixed<N> {
+    type Buf<T> = [T; N];
+
+    fn buf<T>(len: usize, f: impl FnMut(usize) -> T) -> Self::Buf<T> {
+        debug_assert_eq!(len, N);
+        core::array::from_fn(f)
+    }
+}
+
+/// Storage for any number of substreams, as in vecs.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct Dynamic;
+
+#[cfg(feature = "alloc")]
+impl Storage for Dynamic {
+    type Buf<T> = alloc::vec::Vec<T>;
+
+    fn buf<T>(len: usize, f: impl FnMut(usize) -> T) -> Self::Buf<T> {
+        (0..len).map(f).collect()
+    }
+}
+
+/// A trait for making a stream combinator behave as MergeBiased,
+/// CombineLatest, etc.
+///
+/// The combinator takes care of the wakers. On every poll, it reports the
+/// substreams which woke to the behavior, then keeps polling the substreams
+/// the behavior picks until that yields an item or there is nothing left to
+/// pick.
+pub trait StreamBehavior<S: ?Sized> {
+    /// The item of the combined stream.
+    type Item;
+
+    /// The substream at `idx` woke up since the last poll.
+    ///
+    /// Every substream starts out awake. Without per-substream wakers, every
+    /// substream is reported on every poll.
+    fn wake(&mut self, idx: usize);
+
+    /// Pick the next substream to poll, or `None` to stop polling for now.
+    fn next(&mut self) -> Option<usize>;
+
+    /// Poll the substream at `idx`, which was just picked by `next`.
+    fn poll(&mut self, streams: Pin<&mut S>, idx: usize, cx: &mut Context<'_>) -> Step<Self::Item>;
+
+    /// Whether the combined stream has ended, once `next` returns `None`.
+    fn is_done(&self) -> bool;
+}
+
+/// What a stream combinator does after polling a substream.
+#[derive(Debug)]
+pub enum Step<T> {
+    /// Yield an item.
+    Yield(T),
+    /// Pick the next substream to poll.
+    Continue,
+    /// Wait until woken, without polling any other substream.
+    Wait,
+    /// End the combined stream.
+    End,
+}
+
+/// A queue of substream indices, each of which is queued at most once.
+pub(crate) struct IndexQueue<K: Storage> {
+    buf: K::Buf<usize>,
+    start: usize,
+    len: usize,
+}
+
+impl<K: Storage> IndexQueue<K> {
+    pub(crate) fn new(len: usize) -> Self {
+        Self {
+            buf: K::buf(len, |_| 0),
+            start: 0,
+            len: 0,
+        }
+    }
+
+    pub(crate) fn push_back(&mut self, idx: usize) {
+        let buf = self.buf.as_mut();
+        assert!(self.len < buf.len(), "queue is full");
+        buf[(self.start + self.len) % buf.len()] = idx;
+        self.len += 1;
+    }
+
+    pub(crate) fn pop_front(&mut self) -> Option<usize> {
+        if self.len == 0 {
+            return None;
+        }
+        let buf = self.buf.as_ref();
+        let idx = buf[self.start];
+        self.start = (self.start + 1) % buf.len();
+        self.len -= 1;
+        Some(idx)
+    }
+}
@@ -0,0 +1,106 @@
+use super::{IndexedStreams, Slots, SlottedStreams};
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+
+// The tuple is stored as a struct, so every field can be pinned and then
+// polled by its index.
+macro_rules! impl_stream_tuple {
+    ($StructName:ident $($F:ident=$idx:tt)+) => {
+        /// A tuple of streams, which can be polled by index.
+        #[pin_project::pin_project]
+        pub struct $StructName<$($F,)+> { $(#[pin] $F: $F,)+ }
+
+        impl<$($F,)+> $StructName<$($F,)+> {
+            pub(crate) fn new(($($F,)+): ($($F,)+)) -> Self {
+                Self { $($F,)+ }
+            }
+        }
+
+        impl<$($F,)+> fmt::Debug for $StructName<$($F,)+>
+        where $(
+            $F: fmt::Debug,
+        )+ {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("")
+                    $( .field(&self.$F) )+
+                    .finish()
+            }
+        }
+
+        impl<T, $($F,)+> IndexedStreams for $StructName<$($F,)+>
+        where $(
+            $F: Stream<Item = T>,
+        )+ {
+            type Item = T;
+
+            fn poll_next_at(
+                self: Pin<&mut Self>,
+                idx: usize,
+                cx: &mut Context<'_>,
+            ) -> Poll<Option<Self::Item>> {
+                let this = self.project();
+                match idx {
+                    $( $idx => this.$F.poll_next(cx), )+
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        impl<$($F,)+> SlottedStreams for $StructName<$($F,)+>
+        where $(
+            $F: Stream,
+        )+ {
+            type Slots = ($(Option<$F::Item>,)+);
+
+            fn empty_slots(&self) -> Self::Slots {
+                ($(None::<$F::Item>,)+)
+            }
+
+            fn poll_next_into(
+                self: Pin<&mut Self>,
+                idx: usize,
+                cx: &mut Context<'_>,
+                slots: &mut Self::Slots,
+            ) -> Poll<Option<()>> {
+                let this = self.project();
+                match idx {
+                    $(
+                        $idx => this.$F
+                            .poll_next(cx)
+                            .map(|item| item.map(|item| slots.$idx = Some(item))),
+                    )+
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        impl<$($F,)+> Slots for ($(Option<$F>,)+) {
+            type Items = ($($F,)+);
+
+            fn clone_items(&self) -> Self::Items
+            where
+                Self: Clone,
+            {
+                let ($($F,)+) = self.clone();
+                ($($F.expect("every slot must be filled"),)+)
+            }
+        }
+    };
+}
+
+impl_stream_tuple! { Streams1 A=0 }
+impl_stream_tuple! { Streams2 A=0 B=1 }
+impl_stream_tuple! { Streams3 A=0 B=1 C=2 }
+impl_stream_tuple! { Streams4 A=0 B=1 C=2 D=3 }
+impl_stream_tuple! { Streams5 A=0 B=1 C=2 D=3 E=4 }
+impl_stream_tuple! { Streams6 A=0 B=1 C=2 D=3 E=4 F=5 }
+impl_stream_tuple! { Streams7 A=0 B=1 C=2 D=3 E=4 F=5 G=6 }
+impl_stream_tuple! { Streams8 A=0 B=1 C=2 D=3 E=4 F=5 G=6 H=7 }
+impl_stream_tuple! { Streams9 A=0 B=1 C=2 D=3 E=4 F=5 G=6 H=7 I=8 }
+impl_stream_tuple! { Streams10 A=0 B=1 C=2 D=3 E=4 F=5 G=6 H=7 I=8 J=9 }
+impl_stream_tuple! { Streams11 A=0 B=1 C=2 D=3 E=4 F=5 G=6 H=7 I=8 J=9 K=10 }
+impl_stream_tuple! { Streams12 A=0 B=1 C=2 D=3 E=4 F=5 G=6 H=7 I=8 J=9 K=10 L=11 }
@@ -0,0 +1,151 @@
+use super::{IndexedStreams, Slots, SlottedStreams, Step, StreamBehavior};
+use crate::utils::{self, WakerVec};
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+/// A stream combinator over a vec of substreams.
+///
+/// See [`super::StreamCombinatorArray`], which is very similar, for
+/// documentation.
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct StreamCombinatorVec<S, B> {
+    #[pin]
+    streams: Vec<S>,
+    wakers: WakerVec,
+    behavior: B,
+    /// Streams should not be polled after complete.
+    /// Tracked so the stream can report it is terminated, and panic when
+    /// polled again.
+    done: bool,
+}
+
+impl<S, B> StreamCombinatorVec<S, B> {
+    pub(crate) fn new(streams: Vec<S>, behavior: B) -> Self {
+        let len = streams.len();
+        Self {
+            streams,
+            wakers: WakerVec::new(len),
+            behavior,
+            done: false,
+        }
+    }
+}
+
+impl<S, B> fmt::Debug for StreamCombinatorVec<S, B>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.streams.iter()).finish()
+    }
+}
+
+impl<S, B> Stream for StreamCombinatorVec<S, B>
+where
+    B: StreamBehavior<Vec<S>>,
+{
+    type Item = B::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        assert!(!*this.done, "Stream should not be polled after completing");
+
+        {
+            let mut awakeness = this.wakers.awakeness();
+            awakeness.set_parent_waker(cx.waker());
+            for &idx in awakeness.awake_list() {
+                this.behavior.wake(idx);
+            }
+            awakeness.clear();
+        }
+
+        while let Some(idx) = this.behavior.next() {
+            let mut cx = Context::from_waker(this.wakers.get(idx).unwrap());
+            match this.behavior.poll(this.streams.as_mut(), idx, &mut cx) {
+                Step::Yield(item) => return Poll::Ready(Some(item)),
+                Step::Continue => {}
+                Step::Wait => return Poll::Pending,
+                Step::End => {
+                    *this.done = true;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+
+        if this.behavior.is_done() {
+            *this.done = true;
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<S, B> FusedStream for StreamCombinatorVec<S, B>
+where
+    B: StreamBehavior<Vec<S>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<S> IndexedStreams for Vec<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next_at(
+        self: Pin<&mut Self>,
+        idx: usize,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        utils::get_pin_mut_from_vec(self, idx)
+            .unwrap()
+            .poll_next(cx)
+    }
+}
+
+impl<S> SlottedStreams for Vec<S>
+where
+    S: Stream,
+{
+    type Slots = Vec<Option<S::Item>>;
+
+    fn empty_slots(&self) -> Self::Slots {
+        self.iter().map(|_| None).collect()
+    }
+
+    fn poll_next_into(
+        self: Pin<&mut Self>,
+        idx: usize,
+        cx: &mut Context<'_>,
+        slots: &mut Self::Slots,
+    ) -> Poll<Option<()>> {
+        self.poll_next_at(idx, cx)
+            .map(|item| item.map(|item| slots[idx] = Some(item)))
+    }
+}
+
+impl<T> Slots for Vec<Option<T>> {
+    type Items = Vec<T>;
+
+    fn clone_items(&self) -> Self::Items
+    where
+        Self: Clone,
+    {
+        self.clone()
+            .into_iter()
+            .map(|item| item.expect("every slot must be filled"))
+            .collect()
+    }
+}
@@ -0,0 +1,68 @@
+use crate::stream::common::{Fixed, StreamCombinatorArray};
+use crate::stream::IntoStream;
+
+use super::{Interleave as InterleaveTrait, InterleaveBehavior};
+
+/// A stream that takes turns yielding an item from each of multiple streams.
+///
+/// This `struct` is created by the [`interleave`] method on the [`Interleave`]
+/// trait. See its documentation for more.
+///
+/// [`interleave`]: crate::stream::Interleave::interleave
+/// [`Interleave`]: crate::stream::Interleave
+pub type Interleave<S, const N: usize> =
+    StreamCombinatorArray<[S; N], InterleaveBehavior<Fixed<N>>, N>;
+
+impl<S: IntoStream, const N: usize> InterleaveTrait for [S; N] {
+    type Item = S::Item;
+
+    type Stream = Interleave<S::IntoStream, N>;
+
+    fn interleave(self) -> Self::Stream {
+        StreamCombinatorArray::new(self.map(|i| i.into_stream()), InterleaveBehavior::new(N))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::channel::local_channel;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn interleave_3() {
+        block_on(async {
+            let a = stream::iter(vec![1, 2]);
+            let b = stream::iter(vec![3]);
+            let c = stream::iter(vec![4, 5, 6]);
+            let s = [a, b, c].interleave();
+
+            let items: Vec<_> = s.collect().await;
+            assert_eq!(items, [1, 3, 4, 2, 5, 6]);
+        })
+    }
+
+    #[test]
+    fn waits_on_current() {
+        block_on(async {
+            let (send_a, a) = local_channel();
+            let (send_b, b) = local_channel();
+            let mut s = [a, b].interleave();
+
+            send_a.send(1);
+            send_a.send(2);
+            assert_eq!(s.next().await, Some(1));
+            // `b` is pending, so the ready item of `a` has to wait.
+            assert!(futures_lite::future::poll_once(s.next()).await.is_none());
+
+            send_b.send(10);
+            assert_eq!(s.next().await, Some(10));
+            assert_eq!(s.next().await, Some(2));
+            drop(send_b);
+            drop(send_a);
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
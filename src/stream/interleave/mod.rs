@@ -0,0 +1,129 @@
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+
+use crate::stream::common::{IndexedStreams, Step, Storage, StreamBehavior};
+
+pub(crate) mod array;
+pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Takes multiple streams and creates a new stream which takes turns yielding
+/// an item from each of them.
+///
+/// The streams are polled in order, one at a time: after the first stream
+/// yields an item, the second one is polled for the next item, and so on,
+/// wrapping around after the last one. Streams which have ended are skipped.
+/// While the current stream is pending, the interleaved stream waits for it,
+/// even if other streams have items ready. This makes the order of the items
+/// deterministic.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_lite::stream::{self, StreamExt};
+/// use futures_lite::future::block_on;
+///
+/// block_on(async {
+///     let a = stream::iter(vec![1, 2, 3]);
+///     let b = stream::iter(vec![10]);
+///     let c = stream::iter(vec![100, 200]);
+///     let s = [a, b, c].interleave();
+///
+///     let items: Vec<_> = s.collect().await;
+///     assert_eq!(items, vec![1, 10, 100, 2, 200, 3]);
+/// })
+/// ```
+pub trait Interleave {
+    /// What's the return type of our stream?
+    type Item;
+
+    /// What stream do we return?
+    type Stream: Stream<Item = Self::Item>;
+
+    /// Combine multiple streams into a single stream, taking an item from
+    /// each of them in turn.
+    fn interleave(self) -> Self::Stream;
+}
+
+/// The behavior of [`Interleave`] streams: poll the substreams in turn,
+/// waiting for the current one.
+pub struct InterleaveBehavior<K: Storage> {
+    /// The stream whose turn it is.
+    index: usize,
+    /// Which streams have ended.
+    ended: K::Buf<bool>,
+    /// Number of streams that haven't ended.
+    pending: usize,
+}
+
+impl<K: Storage> InterleaveBehavior<K> {
+    pub(crate) fn new(len: usize) -> Self {
+        Self {
+            index: 0,
+            ended: K::buf(len, |_| false),
+            pending: len,
+        }
+    }
+
+    fn advance(&mut self) {
+        self.index = (self.index + 1) % self.ended.as_ref().len();
+    }
+}
+
+impl<K: Storage> fmt::Debug for InterleaveBehavior<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InterleaveBehavior")
+            .field("index", &self.index)
+            .field("ended", &self.ended.as_ref())
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+impl<S, K> StreamBehavior<S> for InterleaveBehavior<K>
+where
+    S: IndexedStreams + ?Sized,
+    K: Storage,
+{
+    type Item = S::Item;
+
+    fn wake(&mut self, _idx: usize) {
+        // Only the current stream matters, and it is polled regardless.
+    }
+
+    fn next(&mut self) -> Option<usize> {
+        if self.pending == 0 {
+            return None;
+        }
+        while self.ended.as_ref()[self.index] {
+            self.advance();
+        }
+        Some(self.index)
+    }
+
+    fn poll(&mut self, streams: Pin<&mut S>, idx: usize, cx: &mut Context<'_>) -> Step<S::Item> {
+        match streams.poll_next_at(idx, cx) {
+            Poll::Ready(Some(item)) => {
+                self.advance();
+                Step::Yield(item)
+            }
+            Poll::Ready(None) => {
+                self.ended.as_mut()[idx] = true;
+                self.pending -= 1;
+                self.advance();
+                Step::Continue
+            }
+            // Wait for the current stream, even if others are ready.
+            Poll::Pending => Step::Wait,
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.pending == 0
+    }
+}
@@ -0,0 +1,59 @@
+use crate::stream::common::*;
+use crate::stream::IntoStream;
+
+use super::{Interleave as InterleaveTrait, InterleaveBehavior};
+
+macro_rules! impl_interleave_tuple {
+    ($StructName:ident $Streams:ident $len:literal; $($F:ident)+) => {
+        pub(crate) type $StructName<$($F,)+> =
+            StreamCombinatorArray<$Streams<$($F,)+>, InterleaveBehavior<Fixed<$len>>, $len>;
+
+        impl<T, $($F),*> InterleaveTrait for ($($F,)*)
+        where $(
+            $F: IntoStream<Item = T>,
+        )* {
+            type Item = T;
+            type Stream = $StructName<$($F::IntoStream,)+>;
+
+            fn interleave(self) -> Self::Stream {
+                let ($($F,)*): ($($F,)*) = self;
+                StreamCombinatorArray::new(
+                    $Streams::new(($($F.into_stream(),)+)),
+                    InterleaveBehavior::new($len),
+                )
+            }
+        }
+    };
+}
+
+impl_interleave_tuple! { Interleave1 Streams1 1; A }
+impl_interleave_tuple! { Interleave2 Streams2 2; A B }
+impl_interleave_tuple! { Interleave3 Streams3 3; A B C }
+impl_interleave_tuple! { Interleave4 Streams4 4; A B C D }
+impl_interleave_tuple! { Interleave5 Streams5 5; A B C D E }
+impl_interleave_tuple! { Interleave6 Streams6 6; A B C D E F }
+impl_interleave_tuple! { Interleave7 Streams7 7; A B C D E F G }
+impl_interleave_tuple! { Interleave8 Streams8 8; A B C D E F G H }
+impl_interleave_tuple! { Interleave9 Streams9 9; A B C D E F G H I }
+impl_interleave_tuple! { Interleave10 Streams10 10; A B C D E F G H I J }
+impl_interleave_tuple! { Interleave11 Streams11 11; A B C D E F G H I J K }
+impl_interleave_tuple! { Interleave12 Streams12 12; A B C D E F G H I J K L }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::{future::block_on, stream, StreamExt};
+
+    #[test]
+    fn interleave_tuple_3() {
+        block_on(async {
+            let a = stream::iter([1, 2]);
+            let b = stream::once(3);
+            let c = stream::iter([4, 5, 6]).boxed_local();
+            let s = (a, b, c).interleave();
+
+            let items: Vec<_> = s.collect().await;
+            assert_eq!(items, [1, 3, 4, 2, 5, 6]);
+        })
+    }
+}
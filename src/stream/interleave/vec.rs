@@ -0,0 +1,56 @@
+use alloc::vec::Vec;
+
+use crate::stream::common::{Dynamic, StreamCombinatorVec};
+use crate::stream::IntoStream;
+
+use super::{Interleave as InterleaveTrait, InterleaveBehavior};
+
+/// A stream that takes turns yielding an item from each of multiple streams.
+///
+/// This `struct` is created by the [`interleave`] method on the [`Interleave`]
+/// trait. See its documentation for more.
+///
+/// [`interleave`]: crate::stream::Interleave::interleave
+/// [`Interleave`]: crate::stream::Interleave
+pub type Interleave<S> = StreamCombinatorVec<S, InterleaveBehavior<Dynamic>>;
+
+impl<S: IntoStream> InterleaveTrait for Vec<S> {
+    type Item = S::Item;
+
+    type Stream = Interleave<S::IntoStream>;
+
+    fn interleave(self) -> Self::Stream {
+        let streams: Vec<_> = self.into_iter().map(|i| i.into_stream()).collect();
+        let len = streams.len();
+        StreamCombinatorVec::new(streams, InterleaveBehavior::new(len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn interleave_3() {
+        block_on(async {
+            let a = stream::iter(vec![1, 2]);
+            let b = stream::iter(vec![3]);
+            let c = stream::iter(vec![4, 5, 6]);
+            let s = vec![a, b, c].interleave();
+
+            let items: Vec<_> = s.collect().await;
+            assert_eq!(items, [1, 3, 4, 2, 5, 6]);
+        })
+    }
+
+    #[test]
+    fn empty() {
+        block_on(async {
+            let mut s = Vec::<stream::Empty<u8>>::new().interleave();
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
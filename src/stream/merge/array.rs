@@ -1,4 +1,5 @@
 use super::Merge as MergeTrait;
+use crate::future::fairness::{Fairness, FairnessState};
 use crate::stream::IntoStream;
 use crate::utils::{self, ArrayDequeue, PollState, WakerArray};
 
@@ -6,7 +7,7 @@ use core::fmt;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 
 /// A stream that merges multiple streams into a single stream.
 ///
@@ -32,10 +33,11 @@ where
     state: [PollState; N],
     /// List of awoken streams.
     awake_list: ArrayDequeue<usize, N>,
+    /// The order in which streams woken at the same time are polled.
+    fairness: FairnessState,
     /// Streams should not be polled after complete.
-    /// In debug, we panic to the user.
-    /// In release, we might sleep or poll substreams after completion.
-    #[cfg(debug_assertions)]
+    /// Tracked so the stream can report it is terminated, and panic when
+    /// polled again.
     done: bool,
 }
 
@@ -48,14 +50,19 @@ where
             streams,
             wakers: WakerArray::new(),
             pending: N,
-            // Start with every substream awake.
-            state: [PollState::Ready; N],
-            // Start with indices of every substream since they're all awake.
-            awake_list: ArrayDequeue::new(core::array::from_fn(core::convert::identity), N),
-            #[cfg(debug_assertions)]
+            // The wakers start out awake, so every substream is queued on the
+            // first poll.
+            state: [PollState::Pending; N],
+            awake_list: ArrayDequeue::new([0; N], 0),
+            fairness: FairnessState::default(),
             done: false,
         }
     }
+
+    pub(crate) fn with_fairness(mut self, fairness: Fairness) -> Self {
+        self.fairness = FairnessState::new(fairness);
+        self
+    }
 }
 
 impl<S, const N: usize> fmt::Debug for Merge<S, N>
@@ -76,7 +83,6 @@ where
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let mut this = self.project();
 
-        #[cfg(debug_assertions)]
         assert!(!*this.done, "Stream should not be polled after completing");
 
         {
@@ -84,10 +90,10 @@ where
             let mut awakeness = this.wakers.awakeness();
             awakeness.set_parent_waker(cx.waker());
 
-            // Copy over the indices of awake substreams.
-            let awake_list = awakeness.awake_list();
+            // Copy over the indices of awake substreams, in the order they should be polled.
+            let awake_list = this.fairness.order(awakeness.awake_list_mut(), N);
             let states = &mut *this.state;
-            this.awake_list.extend(awake_list.iter().filter_map(|&idx| {
+            this.awake_list.extend(awake_list.filter_map(|&idx| {
                 // Only add to our list if the substream is actually pending.
                 // Our awake list will never contain duplicate indices.
                 let state = &mut states[idx];
@@ -137,15 +143,31 @@ where
         }
 
         if *this.pending == 0 {
-            #[cfg(debug_assertions)]
-            {
-                *this.done = true;
-            }
+            *this.done = true;
             Poll::Ready(None)
         } else {
             Poll::Pending
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        utils::size_hint::sum(
+            self.streams
+                .iter()
+                .zip(&self.state)
+                .filter(|(_, state)| **state != PollState::Consumed)
+                .map(|(stream, _)| stream.size_hint()),
+        )
+    }
+}
+
+impl<S, const N: usize> FusedStream for Merge<S, N>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
 }
 
 impl<S, const N: usize> MergeTrait for [S; N]
@@ -158,6 +180,10 @@ where
     fn merge(self) -> Self::Stream {
         Merge::new(self.map(|i| i.into_stream()))
     }
+
+    fn merge_with(self, fairness: Fairness) -> Self::Stream {
+        self.merge().with_fairness(fairness)
+    }
 }
 
 #[cfg(test)]
@@ -254,4 +280,55 @@ mod tests {
             pool.run_until_stalled()
         }
     }
+
+    #[test]
+    fn merge_with_random_fairness() {
+        let firsts: std::collections::HashSet<_> = (0..32)
+            .map(|seed| {
+                let streams = [stream::once(0), stream::once(1), stream::once(2)];
+                let mut s = streams.merge_with(Fairness::Random(seed));
+                block_on(s.next()).unwrap()
+            })
+            .collect();
+        assert_eq!(firsts.len(), 3);
+    }
+
+    #[test]
+    fn fused_in_select() {
+        use futures::StreamExt as _;
+        use futures_core::future::FusedFuture;
+        use std::pin::pin;
+
+        block_on(async {
+            let mut numbers = pin!([stream::once(1), stream::once(2)].merge());
+            let mut total = pin!([std::future::ready(10), std::future::ready(20)].join());
+
+            let mut sum = 0;
+            loop {
+                futures::select! {
+                    n = numbers.select_next_some() => sum += n,
+                    [a, b] = total.as_mut() => sum += a + b,
+                    complete => break,
+                }
+            }
+            assert_eq!(sum, 33);
+            assert!(numbers.is_terminated());
+            assert!(total.is_terminated());
+        })
+    }
+
+    #[test]
+    fn size_hint() {
+        block_on(async {
+            let a = stream::iter(vec![1, 2]);
+            let b = stream::iter(vec![3]);
+            let mut s = [a, b].merge();
+            assert_eq!(s.size_hint(), (3, Some(3)));
+
+            s.next().await;
+            assert_eq!(s.size_hint(), (2, Some(2)));
+            while s.next().await.is_some() {}
+            assert_eq!(s.size_hint(), (0, Some(0)));
+        })
+    }
 }
@@ -1,7 +1,10 @@
+use crate::future::Fairness;
+
 use futures_core::Stream;
 
 pub(crate) mod array;
 pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
 pub(crate) mod vec;
 
 /// Combines multiple streams into a single stream of all their outputs.
@@ -10,6 +13,9 @@ pub(crate) mod vec;
 /// yield until both streams have been exhausted. The output ordering
 /// between streams is not guaranteed.
 ///
+/// All streams must yield the same type of item. To merge streams with
+/// different item types, see [`MergeIndexed`][super::MergeIndexed].
+///
 /// # Examples
 ///
 /// ```
@@ -38,4 +44,40 @@ pub trait Merge {
 
     /// Combine multiple streams into a single stream.
     fn merge(self) -> Self::Stream;
+
+    /// Combine multiple streams into a single stream, picking which stream
+    /// to take an item from first according to `fairness` when several have
+    /// one ready.
+    ///
+    /// Streams which have an item ready but weren't picked are still polled
+    /// before any stream woken later, so no stream is starved regardless of
+    /// the strategy. See [`Fairness`] for the available strategies.
+    ///
+    /// The default implementation ignores `fairness` and calls
+    /// [`merge`][Merge::merge]. The implementations in this crate override it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::future::Fairness;
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::stream::{self, StreamExt};
+    /// use futures_lite::future::block_on;
+    ///
+    /// block_on(async {
+    ///     let a = stream::repeat(1).take(2);
+    ///     let b = stream::repeat(2).take(2);
+    ///     let s = [a, b].merge_with(Fairness::RoundRobin);
+    ///
+    ///     let buf: Vec<_> = s.collect().await;
+    ///     assert_eq!(buf.iter().sum::<i32>(), 6);
+    /// })
+    /// ```
+    fn merge_with(self, fairness: Fairness) -> Self::Stream
+    where
+        Self: Sized,
+    {
+        let _ = fairness;
+        self.merge()
+    }
 }
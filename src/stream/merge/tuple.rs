@@ -1,12 +1,13 @@
 use super::Merge as MergeTrait;
+use crate::future::fairness::{Fairness, FairnessState};
 use crate::stream::IntoStream;
-use crate::utils::{ArrayDequeue, PollState, WakerArray};
+use crate::utils::{self, ArrayDequeue, PollState, WakerArray};
 
 use core::fmt;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 
 macro_rules! impl_merge_tuple {
     ($mod_name:ident $StructName:ident $($F:ident=$fut_idx:tt)+) => {
@@ -34,7 +35,7 @@ macro_rules! impl_merge_tuple {
             pending: usize,
             state: [PollState; $mod_name::LEN],
             awake_list: ArrayDequeue<usize, {$mod_name::LEN}>,
-            #[cfg(debug_assertions)]
+            fairness: FairnessState,
             done: bool
         }
 
@@ -59,15 +60,14 @@ macro_rules! impl_merge_tuple {
             fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
                 let this = self.project();
 
-                #[cfg(debug_assertions)]
-assert!(!*this.done, "Stream should not be polled after completing");
+                assert!(!*this.done, "Stream should not be polled after completing");
 
                 {
                     let mut awakeness = this.wakers.awakeness();
                     awakeness.set_parent_waker(cx.waker());
-                    let awake_list = awakeness.awake_list();
+                    let awake_list = this.fairness.order(awakeness.awake_list_mut(), $mod_name::LEN);
                     let states = &mut *this.state;
-                    this.awake_list.extend(awake_list.iter().filter_map(|&idx| {
+                    this.awake_list.extend(awake_list.filter_map(|&idx| {
                         let state = &mut states[idx];
                         match state {
                             PollState::Pending => {
@@ -114,16 +114,33 @@ assert!(!*this.done, "Stream should not be polled after completing");
                     }
                 }
                 if *this.pending == 0 {
-                    #[cfg(debug_assertions)]
-                    {
-                        *this.done = true;
-                    }
+                    *this.done = true;
                     Poll::Ready(None)
                 }
                 else {
                     Poll::Pending
                 }
             }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let hints = [$(self.streams.$F.size_hint()),+];
+                utils::size_hint::sum(
+                    hints
+                        .into_iter()
+                        .zip(&self.state)
+                        .filter(|(_, state)| **state != PollState::Consumed)
+                        .map(|(hint, _)| hint),
+                )
+            }
+        }
+
+        impl<T, $($F),*> FusedStream for $StructName<T, $($F),*>
+        where $(
+            $F: Stream<Item = T>,
+        )* {
+            fn is_terminated(&self) -> bool {
+                self.done
+            }
         }
 
         impl<T, $($F),*> MergeTrait for ($($F,)*)
@@ -134,14 +151,19 @@ assert!(!*this.done, "Stream should not be polled after completing");
             type Stream = $StructName<T, $($F::IntoStream),*>;
 
             fn merge(self) -> Self::Stream {
+                self.merge_with(Fairness::Biased)
+            }
+
+            fn merge_with(self, fairness: Fairness) -> Self::Stream {
                 let ($($F,)*): ($($F,)*) = self;
                 $StructName {
                     streams: $mod_name::Streams { $($F: $F.into_stream()),+ },
                     wakers: WakerArray::new(),
                     pending: $mod_name::LEN,
-                    state: [PollState::Ready; $mod_name::LEN],
-                    awake_list: ArrayDequeue::new(core::array::from_fn(core::convert::identity), $mod_name::LEN),
-                    #[cfg(debug_assertions)]
+                    // The wakers start out awake, so every substream is queued on the first poll.
+                    state: [PollState::Pending; $mod_name::LEN],
+                    awake_list: ArrayDequeue::new([0; $mod_name::LEN], 0),
+                    fairness: FairnessState::new(fairness),
                     done: false
                 }
             }
@@ -167,6 +189,9 @@ impl MergeTrait for () {
     fn merge(self) -> Self::Stream {
         Merge0
     }
+    fn merge_with(self, _fairness: Fairness) -> Self::Stream {
+        Merge0
+    }
 }
 #[derive(Debug)]
 pub struct Merge0;
@@ -176,6 +201,11 @@ impl Stream for Merge0 {
         Poll::Ready(None)
     }
 }
+impl FusedStream for Merge0 {
+    fn is_terminated(&self) -> bool {
+        true
+    }
+}
 
 #[cfg(test)]
 mod tests {
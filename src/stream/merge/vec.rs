@@ -1,14 +1,16 @@
 use super::Merge as MergeTrait;
+use crate::future::fairness::{Fairness, FairnessState};
 use crate::stream::IntoStream;
 use crate::utils::{self, WakerVec};
 
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
 use core::fmt;
 use core::pin::Pin;
 use core::task::{Context, Poll};
-use std::collections::VecDeque;
 
 use bitvec::vec::BitVec;
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 
 // For code comments, see the array merge code, which is very similar.
 
@@ -31,7 +33,7 @@ where
     consumed: BitVec,
     awake_set: BitVec,
     awake_list: VecDeque<usize>,
-    #[cfg(debug_assertions)]
+    fairness: FairnessState,
     done: bool,
 }
 
@@ -53,10 +55,15 @@ where
             consumed: BitVec::repeat(false, len),
             awake_set: BitVec::repeat(false, len),
             awake_list: VecDeque::with_capacity(len),
-            #[cfg(debug_assertions)]
+            fairness: FairnessState::default(),
             done: false,
         }
     }
+
+    pub(crate) fn with_fairness(mut self, fairness: Fairness) -> Self {
+        self.fairness = FairnessState::new(fairness);
+        self
+    }
 }
 
 impl<S> fmt::Debug for Merge<S>
@@ -77,16 +84,17 @@ where
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let mut this = self.project();
 
-        #[cfg(debug_assertions)]
         assert!(!*this.done, "Stream should not be polled after completing");
 
         {
             let mut awakeness = this.wakers.awakeness();
             awakeness.set_parent_waker(cx.waker());
-            let awake_list = awakeness.awake_list();
+            let awake_list = this
+                .fairness
+                .order(awakeness.awake_list_mut(), this.consumed.len());
             let awake_set = &mut *this.awake_set;
             let consumed = &mut *this.consumed;
-            this.awake_list.extend(awake_list.iter().filter_map(|&idx| {
+            this.awake_list.extend(awake_list.filter_map(|&idx| {
                 // Only add substream that is in !awake && !consumed state.
                 // Set the state to awake in the process.
                 (!awake_set.replace(idx, true) && !consumed[idx]).then_some(idx)
@@ -112,15 +120,31 @@ where
             }
         }
         if *this.pending == 0 {
-            #[cfg(debug_assertions)]
-            {
-                *this.done = true;
-            }
+            *this.done = true;
             Poll::Ready(None)
         } else {
             Poll::Pending
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        utils::size_hint::sum(
+            self.streams
+                .iter()
+                .zip(self.consumed.iter())
+                .filter(|(_, consumed)| !**consumed)
+                .map(|(stream, _)| stream.size_hint()),
+        )
+    }
+}
+
+impl<S> FusedStream for Merge<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
 }
 
 impl<S> MergeTrait for Vec<S>
@@ -133,6 +157,10 @@ where
     fn merge(self) -> Self::Stream {
         Merge::new(self.into_iter().map(|i| i.into_stream()).collect())
     }
+
+    fn merge_with(self, fairness: Fairness) -> Self::Stream {
+        self.merge().with_fairness(fairness)
+    }
 }
 
 #[cfg(test)]
@@ -229,4 +257,34 @@ mod tests {
             pool.run_until_stalled()
         }
     }
+
+    #[test]
+    fn merge_with_round_robin_fairness() {
+        block_on(async {
+            let streams = vec![
+                stream::repeat(0).take(2),
+                stream::repeat(1).take(2),
+                stream::repeat(2).take(2),
+            ];
+            let s = streams.merge_with(Fairness::RoundRobin);
+            let mut out: Vec<_> = s.collect().await;
+            out.sort_unstable();
+            assert_eq!(out, vec![0, 0, 1, 1, 2, 2]);
+        });
+    }
+
+    #[test]
+    fn size_hint() {
+        block_on(async {
+            let a = stream::iter(vec![1, 2]);
+            let b = stream::iter(vec![3]);
+            let s = vec![a.boxed_local(), stream::repeat(4).boxed_local()].merge();
+            assert_eq!(s.size_hint(), (usize::MAX, None));
+
+            let a = stream::iter(vec![1, 2]);
+            let mut s = vec![a, b].merge();
+            s.next().await;
+            assert_eq!(s.size_hint(), (2, Some(2)));
+        })
+    }
 }
@@ -0,0 +1,70 @@
+use super::{MergeBiased as MergeBiasedTrait, MergeBiasedBehavior};
+use crate::stream::common::{Fixed, StreamCombinatorArray};
+use crate::stream::IntoStream;
+
+/// A stream that merges multiple streams into a single stream, preferring the
+/// streams which come first.
+///
+/// This `struct` is created by the [`merge_biased`] method on the
+/// [`MergeBiased`] trait. See its documentation for more.
+///
+/// [`merge_biased`]: crate::stream::MergeBiased::merge_biased
+/// [`MergeBiased`]: crate::stream::MergeBiased
+pub type MergeBiased<S, const N: usize> =
+    StreamCombinatorArray<[S; N], MergeBiasedBehavior<Fixed<N>>, N>;
+
+impl<S, const N: usize> MergeBiasedTrait for [S; N]
+where
+    S: IntoStream,
+{
+    type Item = S::Item;
+    type Stream = MergeBiased<S::IntoStream, N>;
+
+    fn merge_biased(self) -> Self::Stream {
+        StreamCombinatorArray::new(self.map(|i| i.into_stream()), MergeBiasedBehavior::new(N))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::channel::local_channel;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn lower_index_first() {
+        block_on(async {
+            let a = stream::repeat(1).take(3);
+            let b = stream::repeat(2).take(2);
+            let c = stream::repeat(3).take(1);
+            let s = [a, b, c].merge_biased();
+
+            let out: Vec<_> = s.collect().await;
+            assert_eq!(out, vec![1, 1, 1, 2, 2, 3]);
+        })
+    }
+
+    /// A lower-index stream which becomes ready pre-empts the others.
+    #[test]
+    fn preempt() {
+        block_on(async {
+            let (send_control, control) = local_channel();
+            let (send_data, data) = local_channel();
+            let mut s = [control, data].merge_biased();
+
+            send_data.send("a");
+            send_data.send("b");
+            assert_eq!(s.next().await, Some("a"));
+
+            send_control.send("stop");
+            assert_eq!(s.next().await, Some("stop"));
+            assert_eq!(s.next().await, Some("b"));
+
+            drop(send_control);
+            drop(send_data);
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
@@ -0,0 +1,143 @@
+use crate::stream::common::{IndexedStreams, Step, Storage, StreamBehavior};
+use crate::utils::PollState;
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+
+pub(crate) mod array;
+pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Combines multiple streams into a single stream of all their outputs,
+/// preferring the streams which come first.
+///
+/// Whenever several streams have an item ready, the item of the stream with
+/// the lowest index is yielded. A stream is polled again right after it
+/// yielded, so a lower-index stream is drained before any later stream gets a
+/// turn, similar to a `biased` `select!` loop.
+///
+/// A busy stream can starve every stream after it. Use
+/// [`merge_with`][super::Merge::merge_with] when every stream should
+/// make progress.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_lite::stream::{self, StreamExt};
+/// use futures_lite::future::block_on;
+///
+/// block_on(async {
+///     let control = stream::iter(vec!["stop", "start"]);
+///     let data = stream::iter(vec!["a", "b"]);
+///     let s = (control, data).merge_biased();
+///
+///     let buf: Vec<_> = s.collect().await;
+///     assert_eq!(buf, vec!["stop", "start", "a", "b"]);
+/// })
+/// ```
+pub trait MergeBiased {
+    /// The resulting output type.
+    type Item;
+
+    /// The stream type.
+    type Stream: Stream<Item = Self::Item>;
+
+    /// Combine multiple streams into a single stream, preferring the items
+    /// of the streams which come first.
+    fn merge_biased(self) -> Self::Stream;
+}
+
+/// The behavior of [`MergeBiased`] streams: poll the awake substream with the
+/// lowest index first.
+pub struct MergeBiasedBehavior<K: Storage> {
+    /// The states of the substreams.
+    /// Pending = stream is sleeping
+    /// Ready = stream is awake
+    /// Consumed = stream is complete
+    state: K::Buf<PollState>,
+    /// The awake substreams, sorted from the highest index to the lowest, so
+    /// the next one to poll is last.
+    ready: K::Buf<usize>,
+    num_ready: usize,
+    /// Number of substreams that haven't completed.
+    pending: usize,
+}
+
+impl<K: Storage> MergeBiasedBehavior<K> {
+    pub(crate) fn new(len: usize) -> Self {
+        Self {
+            // The wakers start out awake, so every substream is marked awake
+            // on the first poll.
+            state: K::buf(len, |_| PollState::Pending),
+            ready: K::buf(len, |_| 0),
+            num_ready: 0,
+            pending: len,
+        }
+    }
+}
+
+impl<K: Storage> fmt::Debug for MergeBiasedBehavior<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeBiasedBehavior")
+            .field("state", &self.state.as_ref())
+            .field("ready", &&self.ready.as_ref()[..self.num_ready])
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+impl<S, K> StreamBehavior<S> for MergeBiasedBehavior<K>
+where
+    S: IndexedStreams + ?Sized,
+    K: Storage,
+{
+    type Item = S::Item;
+
+    fn wake(&mut self, idx: usize) {
+        let state = &mut self.state.as_mut()[idx];
+        if *state != PollState::Pending {
+            return;
+        }
+        *state = PollState::Ready;
+
+        let ready = &mut self.ready.as_mut()[..=self.num_ready];
+        let pos = ready[..self.num_ready].partition_point(|&other| other > idx);
+        ready.copy_within(pos..self.num_ready, pos + 1);
+        ready[pos] = idx;
+        self.num_ready += 1;
+    }
+
+    fn next(&mut self) -> Option<usize> {
+        let last = self.num_ready.checked_sub(1)?;
+        Some(self.ready.as_ref()[last])
+    }
+
+    fn poll(&mut self, streams: Pin<&mut S>, idx: usize, cx: &mut Context<'_>) -> Step<S::Item> {
+        match streams.poll_next_at(idx, cx) {
+            Poll::Ready(Some(item)) => {
+                // Leave the substream awake, so it is polled first again
+                // next time if no earlier substream woke in the meantime.
+                return Step::Yield(item);
+            }
+            Poll::Ready(None) => {
+                self.pending -= 1;
+                self.state.as_mut()[idx] = PollState::Consumed;
+            }
+            Poll::Pending => {
+                self.state.as_mut()[idx] = PollState::Pending;
+            }
+        }
+        // The substream was picked by `next`, so it is the last awake one.
+        self.num_ready -= 1;
+        Step::Continue
+    }
+
+    fn is_done(&self) -> bool {
+        self.pending == 0
+    }
+}
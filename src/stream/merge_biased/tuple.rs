@@ -0,0 +1,57 @@
+use super::{MergeBiased as MergeBiasedTrait, MergeBiasedBehavior};
+use crate::stream::common::*;
+use crate::stream::IntoStream;
+
+macro_rules! impl_merge_biased_tuple {
+    ($StructName:ident $Streams:ident $len:literal; $($F:ident)+) => {
+        pub(crate) type $StructName<$($F,)+> =
+            StreamCombinatorArray<$Streams<$($F,)+>, MergeBiasedBehavior<Fixed<$len>>, $len>;
+
+        impl<T, $($F),*> MergeBiasedTrait for ($($F,)*)
+        where $(
+            $F: IntoStream<Item = T>,
+        )* {
+            type Item = T;
+            type Stream = $StructName<$($F::IntoStream,)+>;
+
+            fn merge_biased(self) -> Self::Stream {
+                let ($($F,)*): ($($F,)*) = self;
+                StreamCombinatorArray::new(
+                    $Streams::new(($($F.into_stream(),)+)),
+                    MergeBiasedBehavior::new($len),
+                )
+            }
+        }
+    };
+}
+impl_merge_biased_tuple! { MergeBiased1 Streams1 1; A }
+impl_merge_biased_tuple! { MergeBiased2 Streams2 2; A B }
+impl_merge_biased_tuple! { MergeBiased3 Streams3 3; A B C }
+impl_merge_biased_tuple! { MergeBiased4 Streams4 4; A B C D }
+impl_merge_biased_tuple! { MergeBiased5 Streams5 5; A B C D E }
+impl_merge_biased_tuple! { MergeBiased6 Streams6 6; A B C D E F }
+impl_merge_biased_tuple! { MergeBiased7 Streams7 7; A B C D E F G }
+impl_merge_biased_tuple! { MergeBiased8 Streams8 8; A B C D E F G H }
+impl_merge_biased_tuple! { MergeBiased9 Streams9 9; A B C D E F G H I }
+impl_merge_biased_tuple! { MergeBiased10 Streams10 10; A B C D E F G H I J }
+impl_merge_biased_tuple! { MergeBiased11 Streams11 11; A B C D E F G H I J K }
+impl_merge_biased_tuple! { MergeBiased12 Streams12 12; A B C D E F G H I J K L }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn merge_biased_tuple_3() {
+        block_on(async {
+            let a = stream::repeat(1).take(2);
+            let b = stream::once(2);
+            let c = stream::repeat(3).take(2);
+            let out: Vec<_> = (a, b, c).merge_biased().collect().await;
+            assert_eq!(out, vec![1, 1, 2, 3, 3]);
+        })
+    }
+}
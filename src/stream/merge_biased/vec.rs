@@ -0,0 +1,93 @@
+use super::{MergeBiased as MergeBiasedTrait, MergeBiasedBehavior};
+use crate::stream::common::{Dynamic, StreamCombinatorVec};
+use crate::stream::IntoStream;
+
+use alloc::vec::Vec;
+
+/// A stream that merges multiple streams into a single stream, preferring the
+/// streams which come first.
+///
+/// This `struct` is created by the [`merge_biased`] method on the
+/// [`MergeBiased`] trait. See its documentation for more.
+///
+/// [`merge_biased`]: crate::stream::MergeBiased::merge_biased
+/// [`MergeBiased`]: crate::stream::MergeBiased
+pub type MergeBiased<S> = StreamCombinatorVec<S, MergeBiasedBehavior<Dynamic>>;
+
+impl<S> MergeBiasedTrait for Vec<S>
+where
+    S: IntoStream,
+{
+    type Item = S::Item;
+    type Stream = MergeBiased<S::IntoStream>;
+
+    fn merge_biased(self) -> Self::Stream {
+        let streams: Vec<_> = self.into_iter().map(|i| i.into_stream()).collect();
+        let len = streams.len();
+        StreamCombinatorVec::new(streams, MergeBiasedBehavior::new(len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::channel::local_channel;
+    use futures_core::Stream;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+    use std::cell::Cell;
+    use std::pin::Pin;
+    use std::task::Poll;
+
+    #[test]
+    fn lower_index_first() {
+        block_on(async {
+            let streams = vec![
+                stream::repeat(1).take(3),
+                stream::repeat(2).take(2),
+                stream::repeat(3).take(1),
+            ];
+            let out: Vec<_> = streams.merge_biased().collect().await;
+            assert_eq!(out, vec![1, 1, 1, 2, 2, 3]);
+        })
+    }
+
+    #[test]
+    fn preempt() {
+        block_on(async {
+            let (send_control, control) = local_channel();
+            let (send_data, data) = local_channel();
+            let mut s = vec![control, data].merge_biased();
+
+            send_data.send("a");
+            send_data.send("b");
+            assert_eq!(s.next().await, Some("a"));
+
+            send_control.send("stop");
+            assert_eq!(s.next().await, Some("stop"));
+            assert_eq!(s.next().await, Some("b"));
+
+            drop(send_control);
+            drop(send_data);
+            assert_eq!(s.next().await, None);
+        })
+    }
+
+    /// Streams which weren't woken aren't polled again.
+    #[test]
+    fn idle_not_repolled() {
+        block_on(async {
+            let polls = Cell::new(0);
+            let idle = stream::poll_fn(|_| {
+                polls.set(polls.get() + 1);
+                Poll::Pending
+            });
+            let streams: Vec<Pin<Box<dyn Stream<Item = i32>>>> =
+                vec![Box::pin(idle), Box::pin(stream::iter(vec![1, 2, 3]))];
+            let out: Vec<_> = streams.merge_biased().take(3).collect().await;
+            assert_eq!(out, vec![1, 2, 3]);
+            assert_eq!(polls.get(), 1);
+        })
+    }
+}
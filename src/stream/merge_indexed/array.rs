@@ -0,0 +1,55 @@
+use super::{Indexed, MergeIndexed as MergeIndexedTrait};
+use crate::stream::merge::array::Merge;
+use crate::stream::IntoStream;
+
+/// A stream that merges multiple streams into a single stream, tagging each
+/// item with the index of its stream.
+///
+/// This `struct` is created by the [`merge_indexed`] method on the
+/// [`MergeIndexed`] trait. See its documentation for more.
+///
+/// [`merge_indexed`]: crate::stream::MergeIndexed::merge_indexed
+/// [`MergeIndexed`]: crate::stream::MergeIndexed
+pub type MergeIndexed<S, const N: usize> = Merge<Indexed<S>, N>;
+
+impl<S, const N: usize> MergeIndexedTrait for [S; N]
+where
+    S: IntoStream,
+{
+    type Item = (usize, S::Item);
+    type Stream = MergeIndexed<S::IntoStream, N>;
+
+    fn merge_indexed(self) -> Self::Stream {
+        let mut index = 0;
+        Merge::new(self.map(|s| {
+            let indexed = Indexed::new(s.into_stream(), index);
+            index += 1;
+            indexed
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn merge_indexed_array_3() {
+        block_on(async {
+            let a = stream::repeat('a').take(2);
+            let b = stream::repeat('b').take(1);
+            let c = stream::repeat('c').take(3);
+            let mut s = [a, b, c].merge_indexed();
+
+            let mut counts = [0; 3];
+            while let Some((index, item)) = s.next().await {
+                assert_eq!(item, ['a', 'b', 'c'][index]);
+                counts[index] += 1;
+            }
+            assert_eq!(counts, [2, 1, 3]);
+        })
+    }
+}
@@ -0,0 +1,87 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+pub(crate) mod array;
+pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Combines multiple streams into a single stream of all their outputs,
+/// tagged with the stream they came from.
+///
+/// This works like [`Merge`][super::Merge], except that every item is
+/// annotated with the position of the stream that produced it. For arrays and
+/// vectors the item is paired with the index of the stream. For tuples the
+/// item is wrapped in one of the [`select_types`] enums, which means the
+/// streams don't need to share the same item type.
+///
+/// [`select_types`]: crate::future::select_types
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_concurrency::future::select_types::SelectedFrom2;
+/// use futures_lite::stream::{self, StreamExt};
+/// use futures_lite::future::block_on;
+///
+/// block_on(async {
+///     let a = stream::once(1);
+///     let b = stream::once(2);
+///     let mut buf = vec![];
+///     [a, b].merge_indexed().for_each(|item| buf.push(item)).await;
+///     buf.sort_unstable();
+///     assert_eq!(&buf, &[(0, 1), (1, 2)]);
+///
+///     let a = stream::once(1);
+///     let b = stream::once("hello");
+///     let mut s = (a, b).merge_indexed();
+///     while let Some(item) = s.next().await {
+///         match item {
+///             SelectedFrom2::A0(num) => assert_eq!(num, 1),
+///             SelectedFrom2::A1(text) => assert_eq!(text, "hello"),
+///         }
+///     }
+/// })
+/// ```
+pub trait MergeIndexed {
+    /// The resulting output type.
+    type Item;
+
+    /// The stream type.
+    type Stream: Stream<Item = Self::Item>;
+
+    /// Combine multiple streams into a single stream, tagging each item with
+    /// the stream it came from.
+    fn merge_indexed(self) -> Self::Stream;
+}
+
+/// A stream which pairs each item with the index of the stream.
+#[derive(Debug)]
+#[pin_project]
+pub struct Indexed<S> {
+    #[pin]
+    stream: S,
+    index: usize,
+}
+
+impl<S> Indexed<S> {
+    pub(crate) fn new(stream: S, index: usize) -> Self {
+        Self { stream, index }
+    }
+}
+
+impl<S: Stream> Stream for Indexed<S> {
+    type Item = (usize, S::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let index = *this.index;
+        this.stream
+            .poll_next(cx)
+            .map(|item| item.map(|item| (index, item)))
+    }
+}
@@ -0,0 +1,161 @@
+use super::MergeIndexed as MergeIndexedTrait;
+use crate::future::select_types;
+use crate::stream::{IntoStream, Merge as MergeTrait};
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+
+/// A stream which wraps each item into a variant of a `select_types` enum.
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct Select<S: Stream, T> {
+    #[pin]
+    stream: S,
+    select: fn(S::Item) -> T,
+}
+
+impl<S: Stream, T> Select<S, T> {
+    pub(crate) fn new(stream: S, select: fn(S::Item) -> T) -> Self {
+        Self { stream, select }
+    }
+}
+
+impl<S: Stream, T> Stream for Select<S, T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let select = *this.select;
+        this.stream.poll_next(cx).map(|item| item.map(select))
+    }
+}
+
+macro_rules! impl_merge_indexed_tuple {
+    ($SelectedFrom:ident $($F:ident)+) => {
+        impl<$($F),+> MergeIndexedTrait for ($($F,)+)
+        where $(
+            $F: IntoStream,
+        )+ {
+            type Item = select_types::$SelectedFrom<$($F::Item),+>;
+            type Stream = <($(Select<$F::IntoStream, Self::Item>,)+) as MergeTrait>::Stream;
+
+            fn merge_indexed(self) -> Self::Stream {
+                let ($($F,)+) = self;
+                (
+                    $(Select {
+                        stream: $F.into_stream(),
+                        select: select_types::$SelectedFrom::$F,
+                    },)+
+                ).merge()
+            }
+        }
+    };
+}
+
+impl_merge_indexed_tuple! { SelectedFrom1 A0 }
+impl_merge_indexed_tuple! { SelectedFrom2 A0 A1 }
+impl_merge_indexed_tuple! { SelectedFrom3 A0 A1 A2 }
+impl_merge_indexed_tuple! { SelectedFrom4 A0 A1 A2 A3 }
+impl_merge_indexed_tuple! { SelectedFrom5 A0 A1 A2 A3 A4 }
+impl_merge_indexed_tuple! { SelectedFrom6 A0 A1 A2 A3 A4 A5 }
+impl_merge_indexed_tuple! { SelectedFrom7 A0 A1 A2 A3 A4 A5 A6 }
+impl_merge_indexed_tuple! { SelectedFrom8 A0 A1 A2 A3 A4 A5 A6 A7 }
+impl_merge_indexed_tuple! { SelectedFrom9 A0 A1 A2 A3 A4 A5 A6 A7 A8 }
+impl_merge_indexed_tuple! { SelectedFrom10 A0 A1 A2 A3 A4 A5 A6 A7 A8 A9 }
+impl_merge_indexed_tuple! { SelectedFrom11 A0 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 }
+impl_merge_indexed_tuple! { SelectedFrom12 A0 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn merge_indexed_tuple_3() {
+        block_on(async {
+            let a = stream::repeat(1).take(2);
+            let b = stream::once("hello");
+            let c = stream::once('c');
+            let mut s = (a, b, c).merge_indexed();
+
+            let mut counter = 0;
+            let mut text = None;
+            let mut chars = vec![];
+            while let Some(item) = s.next().await {
+                match item {
+                    select_types::SelectedFrom3::A0(num) => counter += num,
+                    select_types::SelectedFrom3::A1(t) => text = Some(t),
+                    select_types::SelectedFrom3::A2(c) => chars.push(c),
+                }
+            }
+            assert_eq!(counter, 2);
+            assert_eq!(text, Some("hello"));
+            assert_eq!(chars, vec!['c']);
+        })
+    }
+
+    /// This test case uses channels so we'll have streams that return Pending from time to time.
+    ///
+    /// The purpose of this test is to make sure we have the waking logic working
+    /// for streams of unrelated item types.
+    #[test]
+    fn merge_indexed_channels() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        use futures::executor::LocalPool;
+        use futures::task::LocalSpawnExt;
+
+        use crate::future::Join;
+        use crate::utils::channel::local_channel;
+
+        let mut pool = LocalPool::new();
+
+        let done = Rc::new(RefCell::new(false));
+        let done2 = done.clone();
+
+        pool.spawner()
+            .spawn_local(async move {
+                let (send1, receive1) = local_channel::<u32>();
+                let (send2, receive2) = local_channel::<&str>();
+                let (send3, receive3) = local_channel::<bool>();
+
+                let (events, ()) = (
+                    async {
+                        (receive1, receive2, receive3)
+                            .merge_indexed()
+                            .collect::<Vec<_>>()
+                            .await
+                    },
+                    async {
+                        send1.send(1);
+                        send2.send("two");
+                        send3.send(true);
+                        send1.send(4);
+                        drop(send1);
+                        drop(send2);
+                        drop(send3);
+                    },
+                )
+                    .join()
+                    .await;
+
+                assert_eq!(events.len(), 4);
+                assert!(events.contains(&select_types::SelectedFrom3::A0(1)));
+                assert!(events.contains(&select_types::SelectedFrom3::A1("two")));
+                assert!(events.contains(&select_types::SelectedFrom3::A2(true)));
+                assert!(events.contains(&select_types::SelectedFrom3::A0(4)));
+
+                *done2.borrow_mut() = true;
+            })
+            .unwrap();
+
+        while !*done.borrow() {
+            pool.run_until_stalled()
+        }
+    }
+}
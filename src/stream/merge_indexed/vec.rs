@@ -0,0 +1,57 @@
+use super::{Indexed, MergeIndexed as MergeIndexedTrait};
+use crate::stream::merge::vec::Merge;
+use crate::stream::IntoStream;
+
+use alloc::vec::Vec;
+
+/// A stream that merges multiple streams into a single stream, tagging each
+/// item with the index of its stream.
+///
+/// This `struct` is created by the [`merge_indexed`] method on the
+/// [`MergeIndexed`] trait. See its documentation for more.
+///
+/// [`merge_indexed`]: crate::stream::MergeIndexed::merge_indexed
+/// [`MergeIndexed`]: crate::stream::MergeIndexed
+pub type MergeIndexed<S> = Merge<Indexed<S>>;
+
+impl<S> MergeIndexedTrait for Vec<S>
+where
+    S: IntoStream,
+{
+    type Item = (usize, S::Item);
+    type Stream = MergeIndexed<S::IntoStream>;
+
+    fn merge_indexed(self) -> Self::Stream {
+        Merge::new(
+            self.into_iter()
+                .enumerate()
+                .map(|(index, s)| Indexed::new(s.into_stream(), index))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn merge_indexed_vec_3() {
+        block_on(async {
+            let a = stream::repeat('a').take(2);
+            let b = stream::repeat('b').take(1);
+            let c = stream::repeat('c').take(3);
+            let mut s = vec![a, b, c].merge_indexed();
+
+            let mut counts = vec![0; 3];
+            while let Some((index, item)) = s.next().await {
+                assert_eq!(item, ['a', 'b', 'c'][index]);
+                counts[index] += 1;
+            }
+            assert_eq!(counts, vec![2, 1, 3]);
+        })
+    }
+}
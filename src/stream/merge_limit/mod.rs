@@ -0,0 +1,64 @@
+use super::IntoStream;
+
+use core::future::IntoFuture;
+use futures_core::Stream;
+
+pub(crate) mod stream;
+
+/// Runs the futures yielded by a stream concurrently, with a limit on how
+/// many futures are polled at once.
+///
+/// This is implemented for any stream of futures. To run the futures of an
+/// iterator, convert it into a stream first, using for example
+/// `futures_lite::stream::iter`.
+pub trait MergeLimit {
+    /// The resulting output type.
+    type Item;
+
+    /// The stream type.
+    type Stream: Stream<Item = Self::Item>;
+
+    /// Run the futures yielded by this stream concurrently, keeping at most
+    /// `limit` futures in flight, and yield their outputs as they complete.
+    ///
+    /// The output ordering between futures is not guaranteed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::stream::{self, StreamExt};
+    /// use futures_lite::future::block_on;
+    /// use std::future;
+    ///
+    /// block_on(async {
+    ///     let futs = stream::iter((0..10).map(future::ready));
+    ///     let mut s = futs.merge_limit(3);
+    ///
+    ///     let mut buf = vec![];
+    ///     while let Some(n) = s.next().await {
+    ///         buf.push(n);
+    ///     }
+    ///     buf.sort_unstable();
+    ///     assert_eq!(buf, (0..10).collect::<Vec<_>>());
+    /// })
+    /// ```
+    fn merge_limit(self, limit: usize) -> Self::Stream;
+}
+
+impl<S> MergeLimit for S
+where
+    S: IntoStream,
+    S::Item: IntoFuture,
+{
+    type Item = <S::Item as IntoFuture>::Output;
+    type Stream = stream::MergeLimit<S::IntoStream>;
+
+    fn merge_limit(self, limit: usize) -> Self::Stream {
+        stream::MergeLimit::new(self.into_stream(), limit)
+    }
+}
@@ -0,0 +1,231 @@
+use crate::utils::{self, WakerVec};
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+type FutureOf<S> = <<S as Stream>::Item as IntoFuture>::IntoFuture;
+
+/// A stream which runs the futures yielded by another stream concurrently,
+/// with a limit on how many futures are polled at once.
+///
+/// This `struct` is created by the [`merge_limit`] method on the
+/// [`MergeLimit`] trait. See its documentation for more.
+///
+/// [`merge_limit`]: crate::stream::MergeLimit::merge_limit
+/// [`MergeLimit`]: crate::stream::MergeLimit
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct MergeLimit<S>
+where
+    S: Stream,
+    S::Item: IntoFuture,
+{
+    #[pin]
+    stream: S,
+    /// Whether `stream` has been exhausted.
+    stream_done: bool,
+    /// The slots which don't currently hold a future.
+    vacant: Vec<usize>,
+    wakers: WakerVec,
+    awake_list_buffer: Vec<usize>,
+    /// The futures in flight. The length of this vector never changes after
+    /// construction, so the slots can be pinned in place.
+    #[pin]
+    slots: Vec<Option<FutureOf<S>>>,
+    done: bool,
+}
+
+impl<S> MergeLimit<S>
+where
+    S: Stream,
+    S::Item: IntoFuture,
+{
+    pub(crate) fn new(stream: S, limit: usize) -> Self {
+        assert!(limit > 0, "the concurrency limit must be greater than zero");
+        Self {
+            stream,
+            stream_done: false,
+            vacant: (0..limit).rev().collect(),
+            wakers: WakerVec::new(limit),
+            awake_list_buffer: Vec::new(),
+            slots: core::iter::repeat_with(|| None).take(limit).collect(),
+            done: false,
+        }
+    }
+}
+
+impl<S> fmt::Debug for MergeLimit<S>
+where
+    S: Stream + fmt::Debug,
+    S::Item: IntoFuture,
+    FutureOf<S>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeLimit")
+            .field("stream", &self.stream)
+            .field(
+                "in_flight",
+                &self.slots.iter().flatten().collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<S> Stream for MergeLimit<S>
+where
+    S: Stream,
+    S::Item: IntoFuture,
+{
+    type Item = <S::Item as IntoFuture>::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        assert!(!*this.done, "Stream should not be polled after completing");
+
+        // Pull new futures out of the stream while there is room for them.
+        while !*this.stream_done {
+            let Some(&slot) = this.vacant.last() else {
+                break;
+            };
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(fut)) => {
+                    this.vacant.pop();
+                    utils::get_pin_mut_from_vec(this.slots.as_mut(), slot)
+                        .unwrap()
+                        .set(Some(fut.into_future()));
+                    this.wakers.get(slot).unwrap().wake_by_ref();
+                }
+                Poll::Ready(None) => *this.stream_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        {
+            let mut awakeness = this.wakers.awakeness();
+            awakeness.set_parent_waker(cx.waker());
+            this.awake_list_buffer.clone_from(awakeness.awake_list());
+            awakeness.clear();
+        }
+
+        for (pos, &slot) in this.awake_list_buffer.iter().enumerate() {
+            let mut fut = utils::get_pin_mut_from_vec(this.slots.as_mut(), slot).unwrap();
+            let Some(inner) = fut.as_mut().as_pin_mut() else {
+                continue;
+            };
+            let mut cx = Context::from_waker(this.wakers.get(slot).unwrap());
+            if let Poll::Ready(value) = inner.poll(&mut cx) {
+                // Drop the future in place, and free up its slot.
+                fut.set(None);
+                this.vacant.push(slot);
+
+                // We're returning before we got to the remaining awake futures.
+                // Wake them again so they are polled next time.
+                for &slot in &this.awake_list_buffer[pos + 1..] {
+                    this.wakers.get(slot).unwrap().wake_by_ref();
+                }
+                return Poll::Ready(Some(value));
+            }
+        }
+
+        if *this.stream_done && this.vacant.len() == this.slots.len() {
+            *this.done = true;
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<S> FusedStream for MergeLimit<S>
+where
+    S: Stream,
+    S::Item: IntoFuture,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::MergeLimit as _;
+    use crate::utils::channel::local_channel;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+    use std::cell::Cell;
+    use std::future;
+
+    #[test]
+    fn empty() {
+        block_on(async {
+            let mut s = stream::empty::<future::Ready<()>>().merge_limit(2);
+            assert_eq!(s.next().await, None);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "greater than zero")]
+    fn zero_limit() {
+        drop(stream::once(future::ready(())).merge_limit(0));
+    }
+
+    /// This test case uses channels so we'll have futures that return Pending
+    /// from time to time, and never more than `limit` futures in flight.
+    #[test]
+    fn limits_in_flight() {
+        block_on(async {
+            let in_flight = Cell::new(0);
+            let max_in_flight = Cell::new(0);
+            let (senders, receivers): (Vec<_>, Vec<_>) =
+                (0..6).map(|_| local_channel::<usize>()).unzip();
+
+            let futs = stream::iter(receivers).map(|mut receiver| {
+                let in_flight = &in_flight;
+                let max_in_flight = &max_in_flight;
+                async move {
+                    in_flight.set(in_flight.get() + 1);
+                    max_in_flight.set(max_in_flight.get().max(in_flight.get()));
+                    let n = receiver.next().await.unwrap();
+                    in_flight.set(in_flight.get() - 1);
+                    n
+                }
+            });
+            let collect = futs.merge_limit(2).collect::<Vec<_>>();
+            let send_all = async {
+                for (i, sender) in senders.iter().enumerate().rev() {
+                    sender.send(i);
+                }
+            };
+            let (mut res, ()) = crate::future::Join::join((collect, send_all)).await;
+            res.sort_unstable();
+            assert_eq!(res, vec![0, 1, 2, 3, 4, 5]);
+            assert_eq!(max_in_flight.get(), 2);
+        });
+    }
+
+    /// The source stream itself may return Pending, too.
+    #[test]
+    fn pending_source() {
+        block_on(async {
+            let (send, receive) = local_channel::<future::Ready<usize>>();
+            let collect = receive.merge_limit(4).collect::<Vec<_>>();
+            let send_all = async move {
+                send.send(future::ready(1));
+                send.send(future::ready(2));
+                futures_lite::future::yield_now().await;
+                send.send(future::ready(3));
+            };
+            let (mut res, ()) = crate::future::Join::join((collect, send_all)).await;
+            res.sort_unstable();
+            assert_eq!(res, vec![1, 2, 3]);
+        });
+    }
+}
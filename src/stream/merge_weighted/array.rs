@@ -0,0 +1,119 @@
+use super::{MergeWeighted as MergeWeightedTrait, MergeWeightedBehavior};
+use crate::stream::common::{Fixed, StreamCombinatorArray};
+use crate::stream::IntoStream;
+
+/// A stream that merges multiple streams into a single stream, sharing
+/// throughput between them in proportion to their weights.
+///
+/// This `struct` is created by the [`merge_weighted`] method on the
+/// [`MergeWeighted`] trait. See its documentation for more.
+///
+/// [`merge_weighted`]: crate::stream::MergeWeighted::merge_weighted
+/// [`MergeWeighted`]: crate::stream::MergeWeighted
+pub type MergeWeighted<S, const N: usize> =
+    StreamCombinatorArray<[S; N], MergeWeightedBehavior<Fixed<N>>, N>;
+
+impl<S, const N: usize> MergeWeightedTrait for [S; N]
+where
+    S: IntoStream,
+{
+    type Item = S::Item;
+    type Weights = [usize; N];
+    type Stream = MergeWeighted<S::IntoStream, N>;
+
+    fn merge_weighted(self, weights: Self::Weights) -> Self::Stream {
+        StreamCombinatorArray::new(
+            self.map(|i| i.into_stream()),
+            MergeWeightedBehavior::new(weights),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::channel::local_channel;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn proportional() {
+        block_on(async {
+            let a = stream::repeat('a');
+            let b = stream::repeat('b');
+            let c = stream::repeat('c');
+            let s = [a, b, c].merge_weighted([3, 1, 2]);
+
+            let out: String = s.take(12).collect().await;
+            assert_eq!(out, "aaabccaaabcc");
+        })
+    }
+
+    #[test]
+    fn exhausted() {
+        block_on(async {
+            let a = stream::repeat(1).take(5);
+            let b = stream::repeat(2).take(2);
+            let out: Vec<_> = [a, b].merge_weighted([2, 1]).collect().await;
+            assert_eq!(out, vec![1, 1, 2, 1, 1, 2, 1]);
+        })
+    }
+
+    /// A stream without items ready gives up the rest of its turn.
+    #[test]
+    fn idle_stream_yields_turn() {
+        block_on(async {
+            let (send_a, a) = local_channel();
+            let (send_b, b) = local_channel();
+            let mut s = [a, b].merge_weighted([3, 1]);
+
+            send_a.send(1);
+            send_b.send(2);
+            send_b.send(2);
+            assert_eq!(s.next().await, Some(1));
+            assert_eq!(s.next().await, Some(2));
+            assert_eq!(s.next().await, Some(2));
+
+            drop(send_a);
+            drop(send_b);
+            assert_eq!(s.next().await, None);
+        })
+    }
+
+    /// A stream which goes pending before its turn is over keeps the rest
+    /// of its deficit for its next turn.
+    #[test]
+    fn deficit_carried_over() {
+        block_on(async {
+            let (send_a, a) = local_channel();
+            let (send_b, b) = local_channel();
+            let mut s = [a, b].merge_weighted([2, 1]);
+
+            send_a.send("a1");
+            for item in ["b1", "b2", "b3"] {
+                send_b.send(item);
+            }
+            assert_eq!(s.next().await, Some("a1"));
+            assert_eq!(s.next().await, Some("b1"));
+
+            for item in ["a2", "a3", "a4"] {
+                send_a.send(item);
+            }
+            // Without the carried over deficit, "b3" would come before "a4".
+            for item in ["b2", "a2", "a3", "a4", "b3"] {
+                assert_eq!(s.next().await, Some(item));
+            }
+
+            drop(send_a);
+            drop(send_b);
+            assert_eq!(s.next().await, None);
+        })
+    }
+
+    #[test]
+    #[should_panic(expected = "weights must be non-zero")]
+    fn zero_weight() {
+        let _ = [stream::once(1), stream::once(2)].merge_weighted([1, 0]);
+    }
+}
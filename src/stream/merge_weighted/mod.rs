@@ -0,0 +1,173 @@
+use crate::stream::common::{IndexQueue, IndexedStreams, Step, Storage, StreamBehavior};
+use crate::utils::PollState;
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+
+pub(crate) mod array;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Combines multiple streams into a single stream of all their outputs,
+/// sharing throughput between them in proportion to their weights.
+///
+/// Streams take turns in the order they were woken. Every turn adds a
+/// stream's weight to its deficit, and the stream yields items until its
+/// deficit is used up or it has no item ready. So when every stream always
+/// has an item ready, a stream with weight 3 yields three times as many items
+/// as a stream with weight 1. Streams which have nothing ready don't hold up
+/// the others. The deficit they have left is carried over to their next turn,
+/// up to one turn's worth, so short bursts still get their share. This is
+/// deficit round robin scheduling with every item costing one.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_lite::stream::{self, StreamExt};
+/// use futures_lite::future::block_on;
+///
+/// block_on(async {
+///     let a = stream::repeat("a");
+///     let b = stream::repeat("b");
+///     let s = [a, b].merge_weighted([3, 1]);
+///
+///     let buf: Vec<_> = s.take(8).collect().await;
+///     assert_eq!(buf, vec!["a", "a", "a", "b", "a", "a", "a", "b"]);
+/// })
+/// ```
+pub trait MergeWeighted {
+    /// The resulting output type.
+    type Item;
+
+    /// The weights of the streams.
+    type Weights;
+
+    /// The stream type.
+    type Stream: Stream<Item = Self::Item>;
+
+    /// Combine multiple streams into a single stream, sharing throughput
+    /// between them according to `weights`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a weight is zero, or for vectors, if there isn't exactly one
+    /// weight per stream.
+    fn merge_weighted(self, weights: Self::Weights) -> Self::Stream;
+}
+
+/// The behavior of [`MergeWeighted`] streams: give the awake substreams turns
+/// in the order they woke, each yielding up to as many items as its weight.
+pub struct MergeWeightedBehavior<K: Storage> {
+    /// The states of the substreams.
+    /// Pending = stream is sleeping
+    /// Ready = stream is awake
+    /// Consumed = stream is complete
+    state: K::Buf<PollState>,
+    /// The awake substreams waiting for their turn.
+    queue: IndexQueue<K>,
+    /// How many items each substream may yield per turn.
+    weights: K::Buf<usize>,
+    /// The substream whose turn it is. It isn't in the queue.
+    current: Option<usize>,
+    /// How many more items each substream may yield, carried over between
+    /// its turns.
+    deficits: K::Buf<usize>,
+    /// Number of substreams that haven't completed.
+    pending: usize,
+}
+
+impl<K: Storage> MergeWeightedBehavior<K> {
+    pub(crate) fn new(weights: K::Buf<usize>) -> Self {
+        assert!(
+            weights.as_ref().iter().all(|&weight| weight > 0),
+            "weights must be non-zero"
+        );
+        let len = weights.as_ref().len();
+        Self {
+            // The wakers start out awake, so every substream is queued on the
+            // first poll.
+            state: K::buf(len, |_| PollState::Pending),
+            queue: IndexQueue::new(len),
+            weights,
+            current: None,
+            deficits: K::buf(len, |_| 0),
+            pending: len,
+        }
+    }
+}
+
+impl<K: Storage> fmt::Debug for MergeWeightedBehavior<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeWeightedBehavior")
+            .field("state", &self.state.as_ref())
+            .field("weights", &self.weights.as_ref())
+            .field("current", &self.current)
+            .field("deficits", &self.deficits.as_ref())
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+impl<S, K> StreamBehavior<S> for MergeWeightedBehavior<K>
+where
+    S: IndexedStreams + ?Sized,
+    K: Storage,
+{
+    type Item = S::Item;
+
+    fn wake(&mut self, idx: usize) {
+        // Awake substreams, including the current one, are already queued.
+        let state = &mut self.state.as_mut()[idx];
+        if *state == PollState::Pending {
+            *state = PollState::Ready;
+            self.queue.push_back(idx);
+        }
+    }
+
+    fn next(&mut self) -> Option<usize> {
+        if self.current.is_none() {
+            // Start the next substream's turn.
+            let idx = self.queue.pop_front()?;
+            self.current = Some(idx);
+            self.deficits.as_mut()[idx] += self.weights.as_ref()[idx];
+        }
+        self.current
+    }
+
+    fn poll(&mut self, streams: Pin<&mut S>, idx: usize, cx: &mut Context<'_>) -> Step<S::Item> {
+        let deficit = &mut self.deficits.as_mut()[idx];
+        match streams.poll_next_at(idx, cx) {
+            Poll::Ready(Some(item)) => {
+                *deficit -= 1;
+                if *deficit == 0 {
+                    // The turn is over. The substream may well have more
+                    // items, so queue it up again while it stays awake.
+                    self.current = None;
+                    self.queue.push_back(idx);
+                }
+                return Step::Yield(item);
+            }
+            Poll::Ready(None) => {
+                self.pending -= 1;
+                self.state.as_mut()[idx] = PollState::Consumed;
+            }
+            Poll::Pending => {
+                // The turn is over. Keep the unused deficit for the next
+                // turn, but never more than a turn's worth, so a stream
+                // which is mostly idle can't save up for a long burst.
+                *deficit = (*deficit).min(self.weights.as_ref()[idx]);
+                self.state.as_mut()[idx] = PollState::Pending;
+            }
+        }
+        self.current = None;
+        Step::Continue
+    }
+
+    fn is_done(&self) -> bool {
+        self.pending == 0
+    }
+}
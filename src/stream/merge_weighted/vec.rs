@@ -0,0 +1,61 @@
+use super::{MergeWeighted as MergeWeightedTrait, MergeWeightedBehavior};
+use crate::stream::common::{Dynamic, StreamCombinatorVec};
+use crate::stream::IntoStream;
+
+use alloc::vec::Vec;
+
+/// A stream that merges multiple streams into a single stream, sharing
+/// throughput between them in proportion to their weights.
+///
+/// This `struct` is created by the [`merge_weighted`] method on the
+/// [`MergeWeighted`] trait. See its documentation for more.
+///
+/// [`merge_weighted`]: crate::stream::MergeWeighted::merge_weighted
+/// [`MergeWeighted`]: crate::stream::MergeWeighted
+pub type MergeWeighted<S> = StreamCombinatorVec<S, MergeWeightedBehavior<Dynamic>>;
+
+impl<S> MergeWeightedTrait for Vec<S>
+where
+    S: IntoStream,
+{
+    type Item = S::Item;
+    type Weights = Vec<usize>;
+    type Stream = MergeWeighted<S::IntoStream>;
+
+    fn merge_weighted(self, weights: Self::Weights) -> Self::Stream {
+        assert_eq!(
+            weights.len(),
+            self.len(),
+            "there must be one weight per stream"
+        );
+        StreamCombinatorVec::new(
+            self.into_iter().map(|i| i.into_stream()).collect(),
+            MergeWeightedBehavior::new(weights),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn proportional() {
+        block_on(async {
+            let streams = vec![stream::repeat('a'), stream::repeat('b')];
+            let s = streams.merge_weighted(vec![3, 1]);
+
+            let out: String = s.take(12).collect().await;
+            assert_eq!(out, "aaabaaabaaab");
+        })
+    }
+
+    #[test]
+    #[should_panic(expected = "there must be one weight per stream")]
+    fn wrong_number_of_weights() {
+        let _ = vec![stream::once(1), stream::once(2)].merge_weighted(vec![1]);
+    }
+}
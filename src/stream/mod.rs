@@ -30,12 +30,63 @@
 //! iterators:
 //!
 //! - `merge`: combine multiple iterators into a single iterator, where the new
-//! iterator yields an item as soon as one is available from one of the
-//! underlying iterators.
+//!   iterator yields an item as soon as one is available from one of the
+//!   underlying iterators.
 //! - `zip`: combine multiple iterators into an iterator of pairs. The
-//! underlying iterators will be awaited concurrently.
+//!   underlying iterators will be awaited concurrently.
+//! - `zip_longest`: like `zip`, but keep going until every iterator has
+//!   finished, yielding `None` in place of the iterators which already did.
+//! - `combine_latest`: combine multiple iterators into an iterator of the
+//!   latest item of each, yielding again whenever any of them yields.
 //! - `chain`: iterate over multiple iterators in sequence. The next iterator in
-//! the sequence won't start until the previous iterator has finished.
+//!   the sequence won't start until the previous iterator has finished.
+//! - `interleave`: take an item from each iterator in turn. Unlike `merge`,
+//!   the order of the items is fixed, at the cost of waiting for the iterator
+//!   whose turn it is.
+//!
+//! ## Selecting
+//!
+//! `merge` requires every iterator to yield the same type of item. To handle
+//! items from unrelated sources, use `merge_indexed` on a tuple. Each item is
+//! then wrapped in one of the [`select_types`][crate::future::select_types]
+//! enums, whose variant tells which iterator it came from. This makes a loop
+//! over a merged tuple a typed replacement for a `select!` loop:
+//!
+//! ```
+//! use futures_concurrency::prelude::*;
+//! use futures_concurrency::future::select_types::SelectedFrom2;
+//! use futures_lite::stream::{self, StreamExt};
+//! use futures_lite::future::block_on;
+//!
+//! enum Command { Stop }
+//!
+//! block_on(async {
+//!     let commands = stream::once(Command::Stop);
+//!     let numbers = stream::iter(vec![1, 2, 3]);
+//!     let mut s = (commands, numbers).merge_indexed();
+//!
+//!     let mut sum = 0;
+//!     while let Some(event) = s.next().await {
+//!         match event {
+//!             SelectedFrom2::A0(Command::Stop) => println!("stop requested"),
+//!             SelectedFrom2::A1(n) => sum += n,
+//!         }
+//!     }
+//!     assert_eq!(sum, 6);
+//! })
+//! ```
+//!
+//! Likewise `chain_indexed` sequences streams of unrelated item types.
+//!
+//! ## Priorities
+//!
+//! `merge_biased` works like `merge`, except that it always yields the item of
+//! the first stream which has one ready. This lets a stream of control
+//! messages pre-empt a stream of data, like a `biased` `select!` loop would.
+//!
+//! `merge_weighted` instead shares throughput between the streams in
+//! proportion to a weight per stream, so that a busy stream can't crowd out
+//! the others.
 //!
 //! ## Futures
 //!
@@ -45,14 +96,41 @@
 //! as `stream::Merge` to be used to execute sets of futures concurrently, but
 //! obtain the individual future's outputs as soon as they're available.
 //!
+//! When there are many futures to run, `stream::MergeLimit` runs the futures
+//! yielded by a stream while keeping only a limited number of them in flight,
+//! yielding their outputs as soon as they're available.
+//!
 //! See the [future concurrency][crate::future#concurrency] documentation for
 //! more on futures concurrency.
 pub use chain::Chain;
+pub use chain_indexed::ChainIndexed;
+pub use combine_latest::CombineLatest;
+pub use interleave::Interleave;
 pub use into_stream::IntoStream;
 pub use merge::Merge;
+pub use merge_biased::MergeBiased;
+pub use merge_indexed::MergeIndexed;
+#[cfg(feature = "alloc")]
+pub use merge_limit::MergeLimit;
+pub use merge_weighted::MergeWeighted;
+#[cfg(feature = "alloc")]
+pub use stream_group::StreamGroup;
 pub use zip::Zip;
+pub use zip_longest::ZipLongest;
 
 pub(crate) mod chain;
+pub(crate) mod chain_indexed;
+pub(crate) mod combine_latest;
+mod common;
+pub(crate) mod interleave;
 mod into_stream;
 pub(crate) mod merge;
+pub(crate) mod merge_biased;
+pub(crate) mod merge_indexed;
+#[cfg(feature = "alloc")]
+pub(crate) mod merge_limit;
+pub(crate) mod merge_weighted;
+#[cfg(feature = "alloc")]
+pub mod stream_group;
 pub(crate) mod zip;
+pub(crate) mod zip_longest;
@@ -0,0 +1,381 @@
+//! A growable group of streams which act as a single unit.
+
+use crate::utils::WakerVec;
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use bitvec::vec::BitVec;
+use futures_core::Stream;
+
+// For code comments, see the `FutureGroup` and vec merge code, which are very similar.
+
+/// A growable group of streams which act as a single unit.
+///
+/// This behaves like [`vec::Merge`][crate::vec::Merge], except that streams
+/// can be inserted into and removed from the group while it is being polled.
+/// Streams which have been exhausted are removed from the group.
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::stream::StreamGroup;
+/// use futures_lite::{stream, StreamExt};
+///
+/// futures_lite::future::block_on(async {
+///     let mut group = StreamGroup::new();
+///     group.insert(stream::once(2));
+///     group.insert(stream::once(4));
+///
+///     let mut out = 0;
+///     while let Some(num) = group.next().await {
+///         out += num;
+///     }
+///     assert_eq!(out, 6);
+/// })
+/// ```
+#[must_use = "`StreamGroup` does nothing if not iterated over"]
+pub struct StreamGroup<S> {
+    streams: Vec<Option<Pin<Box<S>>>>,
+    vacant: Vec<usize>,
+    len: usize,
+    wakers: WakerVec,
+    awake_set: BitVec,
+    awake_list: VecDeque<usize>,
+}
+
+/// A key used to index into the [`StreamGroup`] type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key(usize);
+
+impl<S> StreamGroup<S> {
+    /// Create a new, empty instance of `StreamGroup`.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create a new instance of `StreamGroup` with a given capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            streams: Vec::with_capacity(capacity),
+            vacant: Vec::new(),
+            len: 0,
+            wakers: WakerVec::new(capacity),
+            awake_set: BitVec::repeat(false, capacity),
+            awake_list: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of streams in the group.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the group contains no streams.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the group contains a stream for the given key.
+    pub fn contains_key(&self, key: Key) -> bool {
+        matches!(self.streams.get(key.0), Some(Some(_)))
+    }
+
+    /// Insert a new stream into the group.
+    ///
+    /// The returned key can be used to remove the stream again. Keys of
+    /// streams which have been exhausted or were removed may be handed out
+    /// again.
+    pub fn insert(&mut self, stream: S) -> Key
+    where
+        S: Stream,
+    {
+        let index = match self.vacant.pop() {
+            Some(index) => index,
+            None => {
+                self.streams.push(None);
+                self.streams.len() - 1
+            }
+        };
+        if index >= self.wakers.len() {
+            let len = (self.wakers.len() * 2).max(index + 1);
+            self.wakers.grow(len);
+            self.awake_set.resize(len, false);
+        }
+        self.streams[index] = Some(Box::pin(stream));
+        self.len += 1;
+
+        self.wakers.get(index).unwrap().wake_by_ref();
+        Key(index)
+    }
+
+    /// Remove a stream from the group.
+    ///
+    /// Returns `true` if the group contained a stream for the given key.
+    pub fn remove(&mut self, key: Key) -> bool {
+        match self.streams.get_mut(key.0).and_then(Option::take) {
+            Some(_) => {
+                self.vacant.push(key.0);
+                self.len -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Create a stream which also yields the key of each item.
+    pub fn keyed(self) -> Keyed<S> {
+        Keyed { group: self }
+    }
+}
+
+impl<S> StreamGroup<S>
+where
+    S: Stream,
+{
+    fn poll_next_inner(&mut self, cx: &mut Context<'_>) -> Poll<Option<(Key, S::Item)>> {
+        {
+            let mut awakeness = self.wakers.awakeness();
+            awakeness.set_parent_waker(cx.waker());
+            let awake_set = &mut self.awake_set;
+            self.awake_list.extend(
+                awakeness
+                    .awake_list()
+                    .iter()
+                    .filter_map(|&idx| (!awake_set.replace(idx, true)).then_some(idx)),
+            );
+            awakeness.clear();
+        }
+
+        while let Some(idx) = self.awake_list.pop_front() {
+            self.awake_set.set(idx, false);
+            let stream = match self.streams.get_mut(idx) {
+                Some(Some(stream)) => stream,
+                _ => continue,
+            };
+            let waker = self.wakers.get(idx).unwrap();
+            let mut cx = Context::from_waker(waker);
+            match stream.as_mut().poll_next(&mut cx) {
+                Poll::Ready(Some(item)) => {
+                    // Queue the substream to be polled again next time.
+                    waker.wake_by_ref();
+                    return Poll::Ready(Some((Key(idx), item)));
+                }
+                Poll::Ready(None) => {
+                    self.streams[idx] = None;
+                    self.vacant.push(idx);
+                    self.len -= 1;
+                }
+                Poll::Pending => {}
+            }
+        }
+
+        if self.len == 0 {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<S> Default for StreamGroup<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> fmt::Debug for StreamGroup<S>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.streams.iter().flatten())
+            .finish()
+    }
+}
+
+impl<S> Stream for StreamGroup<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut()
+            .poll_next_inner(cx)
+            .map(|item| item.map(|(_, item)| item))
+    }
+}
+
+/// A stream over a [`StreamGroup`] which yields the key of each item.
+///
+/// This `struct` is created by the [`keyed`] method on [`StreamGroup`]. See its
+/// documentation for more.
+///
+/// [`keyed`]: StreamGroup::keyed
+#[must_use = "streams do nothing unless polled or .awaited"]
+pub struct Keyed<S> {
+    group: StreamGroup<S>,
+}
+
+impl<S> Deref for Keyed<S> {
+    type Target = StreamGroup<S>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.group
+    }
+}
+
+impl<S> DerefMut for Keyed<S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.group
+    }
+}
+
+impl<S> fmt::Debug for Keyed<S>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.group.fmt(f)
+    }
+}
+
+impl<S> Stream for Keyed<S>
+where
+    S: Stream,
+{
+    type Item = (Key, S::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().group.poll_next_inner(cx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::channel::local_channel;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let mut group = StreamGroup::new();
+            group.insert(stream::repeat(1).take(2));
+            group.insert(stream::repeat(2).take(2));
+
+            let mut out = 0;
+            while let Some(num) = group.next().await {
+                out += num;
+            }
+            assert_eq!(out, 6);
+            assert!(group.is_empty());
+        });
+    }
+
+    #[test]
+    fn keyed() {
+        block_on(async {
+            let mut group = StreamGroup::new();
+            let a = group.insert(stream::once("a"));
+            let b = group.insert(stream::once("b"));
+            let mut group = group.keyed();
+
+            let mut out = vec![];
+            while let Some((key, item)) = group.next().await {
+                out.push((key, item));
+            }
+            out.sort();
+            assert_eq!(out, vec![(a, "a"), (b, "b")]);
+        });
+    }
+
+    #[test]
+    fn remove() {
+        block_on(async {
+            let mut group = StreamGroup::new();
+            let a = group.insert(stream::repeat("a").take(usize::MAX));
+            let b = group.insert(stream::repeat("b").take(1));
+            assert_eq!(group.len(), 2);
+
+            assert!(group.remove(a));
+            assert!(!group.contains_key(a));
+            assert!(group.contains_key(b));
+
+            assert_eq!(group.next().await, Some("b"));
+            assert_eq!(group.next().await, None);
+        });
+    }
+
+    /// This test case uses channels so we'll have streams that return Pending from time to time.
+    ///
+    /// The purpose of this test is to make sure we have the waking logic working,
+    /// also for streams which get subscribed to while the group is in use.
+    #[test]
+    fn insert_channels() {
+        block_on(async {
+            let (send1, receive1) = local_channel();
+            let (send2, receive2) = local_channel();
+            let mut group = StreamGroup::new();
+            group.insert(receive1);
+
+            send1.send(1);
+            assert_eq!(group.next().await, Some(1));
+
+            group.insert(receive2);
+            send2.send(2);
+            send1.send(3);
+            drop(send1);
+            drop(send2);
+
+            let mut out = vec![];
+            while let Some(num) = group.next().await {
+                out.push(num);
+            }
+            out.sort_unstable();
+            assert_eq!(out, vec![2, 3]);
+        });
+    }
+
+    /// Inserting past the capacity rebuilds the wakers, which must not swallow
+    /// the wakeup for the new stream while the group is parked.
+    #[test]
+    fn insert_past_capacity_while_parked() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use std::task::{Wake, Waker};
+
+        struct CountingWaker(AtomicUsize);
+
+        impl Wake for CountingWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = Waker::from(counter.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        let mut group = StreamGroup::with_capacity(1);
+        group.insert(stream::pending().boxed());
+        assert!(Pin::new(&mut group).poll_next(&mut cx).is_pending());
+
+        let woken = counter.0.load(Ordering::SeqCst);
+        group.insert(stream::once(1).boxed());
+        assert!(counter.0.load(Ordering::SeqCst) > woken);
+        assert_eq!(
+            Pin::new(&mut group).poll_next(&mut cx),
+            Poll::Ready(Some(1))
+        );
+    }
+}
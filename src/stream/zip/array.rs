@@ -8,7 +8,7 @@ use core::mem::MaybeUninit;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use pin_project::{pin_project, pinned_drop};
 
 /// A stream that ‘zips up’ multiple streams into a single stream of pairs.
@@ -46,9 +46,8 @@ where
     #[pin]
     streams: [S; N],
     /// Streams should not be polled after complete.
-    /// In debug, we panic to the user.
-    /// In release, we might sleep or poll substreams after completion.
-    #[cfg(debug_assertions)]
+    /// Tracked so the stream can report it is terminated, and panic when
+    /// polled again.
     done: bool,
 }
 
@@ -65,7 +64,6 @@ where
             // TODO: this is a temporary buffer so it can be MaybeUninit.
             awake_list_buffer: [0; N],
             pending: N,
-            #[cfg(debug_assertions)]
             done: false,
         }
     }
@@ -89,7 +87,6 @@ where
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let mut this = self.project();
 
-        #[cfg(debug_assertions)]
         assert!(!*this.done, "Stream should not be polled after completing");
 
         let num_awake = {
@@ -134,10 +131,7 @@ where
                 Poll::Ready(None) => {
                     // If one substream ends, the entire Zip ends.
 
-                    #[cfg(debug_assertions)]
-                    {
-                        *this.done = true;
-                    }
+                    *this.done = true;
 
                     return Poll::Ready(None);
                 }
@@ -166,6 +160,30 @@ where
             Poll::Pending
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        // Items which were already buffered are part of the next zipped item.
+        utils::size_hint::min(
+            self.streams
+                .iter()
+                .zip(&self.filled)
+                .map(|(stream, &filled)| {
+                    utils::size_hint::add(stream.size_hint(), filled as usize)
+                }),
+        )
+    }
+}
+
+impl<S, const N: usize> FusedStream for Zip<S, N>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
 }
 
 /// Drop the already initialized values on cancellation.
@@ -218,4 +236,17 @@ mod tests {
             assert_eq!(s.next().await, None);
         })
     }
+
+    #[test]
+    fn size_hint() {
+        block_on(async {
+            let a = stream::iter(vec![1, 2, 3]);
+            let b = stream::iter(vec![4, 5]);
+            let mut s = [a, b].zip();
+            assert_eq!(s.size_hint(), (2, Some(2)));
+
+            assert_eq!(s.next().await, Some([1, 4]));
+            assert_eq!(s.size_hint(), (1, Some(1)));
+        })
+    }
 }
@@ -1,13 +1,13 @@
 use super::Zip as ZipTrait;
 use crate::stream::IntoStream;
-use crate::utils::WakerArray;
+use crate::utils::{self, WakerArray};
 
 use core::fmt;
 use core::mem::MaybeUninit;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use pin_project::{pin_project, pinned_drop};
 
 macro_rules! impl_zip_tuple {
@@ -37,7 +37,6 @@ macro_rules! impl_zip_tuple {
 			filled: [bool; $mod_name::LEN],
             awake_list_buffer: [usize; $mod_name::LEN],
             pending: usize,
-            #[cfg(debug_assertions)]
             done: bool
         }
 
@@ -61,8 +60,7 @@ macro_rules! impl_zip_tuple {
             fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
                 let this = self.project();
 
-                #[cfg(debug_assertions)]
-assert!(!*this.done, "Stream should not be polled after completing");
+                assert!(!*this.done, "Stream should not be polled after completing");
 
 				let num_awake = {
 					let mut awakeness = this.wakers.awakeness();
@@ -101,10 +99,7 @@ assert!(!*this.done, "Stream should not be polled after completing");
                                         *this.pending -= 1;
 									}
                                     Poll::Ready(None) => {
-                                        #[cfg(debug_assertions)]
-                                        {
-                                            *this.done = true;
-                                        }
+                                        *this.done = true;
                                         return Poll::Ready(None);
                                     }
                                     Poll::Pending => {}
@@ -132,6 +127,28 @@ assert!(!*this.done, "Stream should not be polled after completing");
                     Poll::Pending
                 }
             }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.done {
+                    return (0, Some(0));
+                }
+                let hints = [$(self.streams.$F.size_hint()),+];
+                utils::size_hint::min(
+                    hints
+                        .into_iter()
+                        .zip(&self.filled)
+                        .map(|(hint, &filled)| utils::size_hint::add(hint, filled as usize)),
+                )
+            }
+        }
+
+        impl<$($F),*> FusedStream for $StructName<$($F),*>
+        where $(
+            $F: Stream,
+        )* {
+            fn is_terminated(&self) -> bool {
+                self.done
+            }
         }
 
         impl<$($F),*> ZipTrait for ($($F,)*)
@@ -150,7 +167,6 @@ assert!(!*this.done, "Stream should not be polled after completing");
                     filled: [false; $mod_name::LEN],
                     awake_list_buffer: [0; $mod_name::LEN],
                     pending: $mod_name::LEN,
-                    #[cfg(debug_assertions)]
                     done: false
                 }
             }
@@ -203,6 +219,11 @@ impl Stream for Zip0 {
         Poll::Ready(Some(()))
     }
 }
+impl FusedStream for Zip0 {
+    fn is_terminated(&self) -> bool {
+        true
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -222,4 +243,23 @@ mod tests {
             assert_eq!(s.next().await, None);
         })
     }
+
+    #[test]
+    fn size_hint_counts_buffered_items() {
+        use crate::utils::channel::local_channel;
+
+        block_on(async {
+            let (send, receive) = local_channel();
+            let mut s = (stream::iter(vec![1, 2, 3]), receive).zip();
+            assert_eq!(s.size_hint(), (0, Some(3)));
+
+            // The first item of the iterator is buffered while the channel is empty.
+            assert!(futures_lite::future::poll_once(s.next()).await.is_none());
+            assert_eq!(s.size_hint(), (0, Some(3)));
+
+            send.send('a');
+            assert_eq!(s.next().await, Some((1, 'a')));
+            assert_eq!(s.size_hint(), (0, Some(2)));
+        })
+    }
 }
@@ -2,13 +2,14 @@ use super::Zip as ZipTrait;
 use crate::stream::IntoStream;
 use crate::utils::{self, WakerVec};
 
+use alloc::vec::Vec;
 use core::fmt;
 use core::mem::MaybeUninit;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
 use bitvec::vec::BitVec;
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use pin_project::{pin_project, pinned_drop};
 
 // For code comments, see the array zip code, which is very similar.
@@ -32,7 +33,6 @@ where
     filled: BitVec,
     awake_list_buffer: Vec<usize>,
     pending: usize,
-    #[cfg(debug_assertions)]
     done: bool,
 }
 
@@ -49,7 +49,6 @@ where
             filled: BitVec::repeat(false, len),
             awake_list_buffer: Vec::new(),
             pending: len,
-            #[cfg(debug_assertions)]
             done: false,
         }
     }
@@ -73,7 +72,6 @@ where
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let mut this = self.project();
 
-        #[cfg(debug_assertions)]
         assert!(!*this.done, "Stream should not be polled after completing");
 
         let len = this.streams.len();
@@ -104,10 +102,7 @@ where
                     *this.pending -= 1;
                 }
                 Poll::Ready(None) => {
-                    #[cfg(debug_assertions)]
-                    {
-                        *this.done = true;
-                    }
+                    *this.done = true;
                     return Poll::Ready(None);
                 }
                 Poll::Pending => {}
@@ -132,6 +127,29 @@ where
             Poll::Pending
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        utils::size_hint::min(
+            self.streams
+                .iter()
+                .zip(self.filled.iter())
+                .map(|(stream, filled)| {
+                    utils::size_hint::add(stream.size_hint(), *filled as usize)
+                }),
+        )
+    }
+}
+
+impl<S> FusedStream for Zip<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
 }
 
 /// Drop the already initialized values on cancellation.
@@ -165,6 +183,19 @@ where
     }
 }
 
+// Inlined version of the unstable `MaybeUninit::array_assume_init` feature.
+// FIXME: replace with `utils::array_assume_init`
+unsafe fn vec_assume_init<T>(vec: Vec<MaybeUninit<T>>) -> Vec<T> {
+    // SAFETY:
+    // * The caller guarantees that all elements of the vec are initialized
+    // * `MaybeUninit<T>` and T are guaranteed to have the same layout
+    // * `MaybeUninit` does not drop, so there are no double-frees
+    // And thus the conversion is safe
+    let ret = unsafe { (&vec as *const _ as *const Vec<T>).read() };
+    core::mem::forget(vec);
+    ret
+}
+
 #[cfg(test)]
 mod tests {
     use crate::stream::Zip;
@@ -186,16 +217,3 @@ mod tests {
         })
     }
 }
-
-// Inlined version of the unstable `MaybeUninit::array_assume_init` feature.
-// FIXME: replace with `utils::array_assume_init`
-unsafe fn vec_assume_init<T>(vec: Vec<MaybeUninit<T>>) -> Vec<T> {
-    // SAFETY:
-    // * The caller guarantees that all elements of the vec are initialized
-    // * `MaybeUninit<T>` and T are guaranteed to have the same layout
-    // * `MaybeUninit` does not drop, so there are no double-frees
-    // And thus the conversion is safe
-    let ret = unsafe { (&vec as *const _ as *const Vec<T>).read() };
-    core::mem::forget(vec);
-    ret
-}
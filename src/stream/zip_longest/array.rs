@@ -0,0 +1,86 @@
+use super::{AllEnded, Padded, PaddedZip, ZipLongest as ZipLongestTrait};
+use crate::stream::zip::array::Zip;
+use crate::stream::{IntoStream, Zip as _};
+
+/// A stream that ‘zips up’ multiple streams into a single stream of pairs,
+/// until every stream has been exhausted.
+///
+/// This `struct` is created by the [`zip_longest`] method on the
+/// [`ZipLongest`] trait. See its documentation for more.
+///
+/// [`zip_longest`]: crate::stream::ZipLongest::zip_longest
+/// [`ZipLongest`]: crate::stream::ZipLongest
+pub type ZipLongest<S, const N: usize> = PaddedZip<Zip<Padded<S>, N>>;
+
+impl<T, const N: usize> AllEnded for [Option<T>; N] {
+    fn all_ended(&self) -> bool {
+        self.iter().all(Option::is_none)
+    }
+}
+
+impl<S, const N: usize> ZipLongestTrait for [S; N]
+where
+    S: IntoStream,
+{
+    type Item = [Option<S::Item>; N];
+    type Stream = ZipLongest<S::IntoStream, N>;
+
+    fn zip_longest(self) -> Self::Stream {
+        PaddedZip::new(self.map(|i| Padded::new(i.into_stream())).zip())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::channel::local_channel;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn zip_longest_array_3() {
+        block_on(async {
+            let a = stream::repeat(1).take(1);
+            let b = stream::repeat(2).take(3);
+            let c = stream::repeat(3).take(2);
+            let mut s = [a, b, c].zip_longest();
+
+            assert_eq!(s.next().await, Some([Some(1), Some(2), Some(3)]));
+            assert_eq!(s.next().await, Some([None, Some(2), Some(3)]));
+            assert_eq!(s.next().await, Some([None, Some(2), None]));
+            assert_eq!(s.next().await, None);
+        })
+    }
+
+    #[test]
+    fn zip_longest_same_length() {
+        block_on(async {
+            let a = stream::repeat(1).take(2);
+            let b = stream::repeat(2).take(2);
+            let out: Vec<_> = [a, b].zip_longest().collect().await;
+            assert_eq!(out, vec![[Some(1), Some(2)], [Some(1), Some(2)]]);
+        })
+    }
+
+    /// This test case uses channels so we'll have streams that return Pending from time to time.
+    #[test]
+    fn zip_longest_channels() {
+        block_on(async {
+            let (send_a, a) = local_channel();
+            let (send_b, b) = local_channel();
+            let mut s = [a, b].zip_longest();
+
+            send_a.send(1);
+            drop(send_a);
+            send_b.send(2);
+            assert_eq!(s.next().await, Some([Some(1), Some(2)]));
+
+            send_b.send(3);
+            assert_eq!(s.next().await, Some([None, Some(3)]));
+
+            drop(send_b);
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
@@ -0,0 +1,144 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+pub(crate) mod array;
+pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// ‘Zips up’ multiple streams into a single stream of pairs, until every
+/// stream has been exhausted.
+///
+/// This works like [`Zip`][super::Zip], except that the stream only ends once
+/// all streams have ended, rather than as soon as any stream ends. Every item
+/// of every stream is wrapped in `Some`; streams which have already ended are
+/// represented by `None`.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_lite::stream::{self, StreamExt};
+/// use futures_lite::future::block_on;
+///
+/// block_on(async {
+///     let a = stream::iter(vec![1, 2]);
+///     let b = stream::iter(vec!["a"]);
+///     let mut s = (a, b).zip_longest();
+///
+///     assert_eq!(s.next().await, Some((Some(1), Some("a"))));
+///     assert_eq!(s.next().await, Some((Some(2), None)));
+///     assert_eq!(s.next().await, None);
+/// })
+/// ```
+pub trait ZipLongest {
+    /// What's the return type of our stream?
+    type Item;
+
+    /// What stream do we return?
+    type Stream: Stream<Item = Self::Item>;
+
+    /// Combine multiple streams into a single stream, which ends once every
+    /// stream has ended.
+    fn zip_longest(self) -> Self::Stream;
+}
+
+/// A stream which keeps yielding `None` once the wrapped stream has ended.
+///
+/// Zipping these lets [`Zip`][super::Zip] do the buffering for
+/// [`ZipLongest`]: a substream which has ended fills its slot right away.
+#[derive(Debug)]
+#[pin_project]
+pub struct Padded<S> {
+    #[pin]
+    stream: S,
+    ended: bool,
+}
+
+impl<S> Padded<S> {
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream,
+            ended: false,
+        }
+    }
+}
+
+impl<S: Stream> Stream for Padded<S> {
+    type Item = Option<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if *this.ended {
+            return Poll::Ready(Some(None));
+        }
+        match this.stream.poll_next(cx) {
+            Poll::Ready(Some(item)) => Poll::Ready(Some(Some(item))),
+            Poll::Ready(None) => {
+                *this.ended = true;
+                Poll::Ready(Some(None))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// The items of every substream, which can tell whether all of them ended.
+pub trait AllEnded {
+    /// Whether every substream has ended, so none of them has an item.
+    fn all_ended(&self) -> bool;
+}
+
+/// Zips [`Padded`] streams, and ends once every substream has ended.
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[derive(Debug)]
+#[pin_project]
+pub struct PaddedZip<Z> {
+    #[pin]
+    zip: Z,
+    done: bool,
+}
+
+impl<Z> PaddedZip<Z> {
+    pub(crate) fn new(zip: Z) -> Self {
+        Self { zip, done: false }
+    }
+}
+
+impl<Z> Stream for PaddedZip<Z>
+where
+    Z: Stream,
+    Z::Item: AllEnded,
+{
+    type Item = Z::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        assert!(!*this.done, "Stream should not be polled after completing");
+
+        match this.zip.poll_next(cx) {
+            Poll::Ready(Some(items)) if !items.all_ended() => Poll::Ready(Some(items)),
+            // The padded streams never end, so the zip only ends if there are
+            // no substreams at all.
+            Poll::Ready(_) => {
+                *this.done = true;
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<Z> FusedStream for PaddedZip<Z>
+where
+    Z: Stream,
+    Z::Item: AllEnded,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
@@ -0,0 +1,63 @@
+use super::{AllEnded, Padded, PaddedZip, ZipLongest as ZipLongestTrait};
+use crate::stream::{IntoStream, Zip as ZipTrait};
+
+macro_rules! impl_zip_longest_tuple {
+    ($StructName:ident; $($F:ident=$idx:tt)+) => {
+        pub(crate) type $StructName<$($F,)+> =
+            PaddedZip<<($(Padded<$F>,)+) as ZipTrait>::Stream>;
+
+        impl<$($F,)+> AllEnded for ($(Option<$F>,)+) {
+            fn all_ended(&self) -> bool {
+                $(self.$idx.is_none())&&+
+            }
+        }
+
+        impl<$($F),*> ZipLongestTrait for ($($F,)*)
+        where $(
+            $F: IntoStream,
+        )* {
+            type Item = ($(Option<$F::Item>,)+);
+            type Stream = $StructName<$($F::IntoStream,)+>;
+
+            fn zip_longest(self) -> Self::Stream {
+                let ($($F,)*): ($($F,)*) = self;
+                PaddedZip::new(($(Padded::new($F.into_stream()),)+).zip())
+            }
+        }
+    };
+}
+
+impl_zip_longest_tuple! { ZipLongest1; A=0 }
+impl_zip_longest_tuple! { ZipLongest2; A=0 B=1 }
+impl_zip_longest_tuple! { ZipLongest3; A=0 B=1 C=2 }
+impl_zip_longest_tuple! { ZipLongest4; A=0 B=1 C=2 D=3 }
+impl_zip_longest_tuple! { ZipLongest5; A=0 B=1 C=2 D=3 E=4 }
+impl_zip_longest_tuple! { ZipLongest6; A=0 B=1 C=2 D=3 E=4 F=5 }
+impl_zip_longest_tuple! { ZipLongest7; A=0 B=1 C=2 D=3 E=4 F=5 G=6 }
+impl_zip_longest_tuple! { ZipLongest8; A=0 B=1 C=2 D=3 E=4 F=5 G=6 H=7 }
+impl_zip_longest_tuple! { ZipLongest9; A=0 B=1 C=2 D=3 E=4 F=5 G=6 H=7 I=8 }
+impl_zip_longest_tuple! { ZipLongest10; A=0 B=1 C=2 D=3 E=4 F=5 G=6 H=7 I=8 J=9 }
+impl_zip_longest_tuple! { ZipLongest11; A=0 B=1 C=2 D=3 E=4 F=5 G=6 H=7 I=8 J=9 K=10 }
+impl_zip_longest_tuple! { ZipLongest12; A=0 B=1 C=2 D=3 E=4 F=5 G=6 H=7 I=8 J=9 K=10 L=11 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::{future::block_on, stream, StreamExt};
+
+    #[test]
+    fn zip_longest_tuple_3() {
+        block_on(async {
+            let mut s = (
+                stream::repeat(3).take(3),
+                stream::once("hello"),
+                stream::once(1).chain(stream::once(5)),
+            )
+                .zip_longest();
+            assert_eq!(s.next().await, Some((Some(3), Some("hello"), Some(1))));
+            assert_eq!(s.next().await, Some((Some(3), None, Some(5))));
+            assert_eq!(s.next().await, Some((Some(3), None, None)));
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
@@ -0,0 +1,65 @@
+use super::{AllEnded, Padded, PaddedZip, ZipLongest as ZipLongestTrait};
+use crate::stream::zip::vec::Zip;
+use crate::stream::{IntoStream, Zip as _};
+
+use alloc::vec::Vec;
+
+/// A stream that ‘zips up’ multiple streams into a single stream of pairs,
+/// until every stream has been exhausted.
+///
+/// This `struct` is created by the [`zip_longest`] method on the
+/// [`ZipLongest`] trait. See its documentation for more.
+///
+/// [`zip_longest`]: crate::stream::ZipLongest::zip_longest
+/// [`ZipLongest`]: crate::stream::ZipLongest
+pub type ZipLongest<S> = PaddedZip<Zip<Padded<S>>>;
+
+impl<T> AllEnded for Vec<Option<T>> {
+    fn all_ended(&self) -> bool {
+        self.iter().all(Option::is_none)
+    }
+}
+
+impl<S> ZipLongestTrait for Vec<S>
+where
+    S: IntoStream,
+{
+    type Item = Vec<Option<S::Item>>;
+    type Stream = ZipLongest<S::IntoStream>;
+
+    fn zip_longest(self) -> Self::Stream {
+        let streams: Vec<_> = self
+            .into_iter()
+            .map(|i| Padded::new(i.into_stream()))
+            .collect();
+        PaddedZip::new(streams.zip())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn zip_longest_vec_3() {
+        block_on(async {
+            let streams = vec![
+                stream::repeat(1).take(1),
+                stream::repeat(2).take(3),
+                stream::repeat(3).take(2),
+            ];
+            let out: Vec<_> = streams.zip_longest().collect().await;
+            assert_eq!(
+                out,
+                vec![
+                    vec![Some(1), Some(2), Some(3)],
+                    vec![None, Some(2), Some(3)],
+                    vec![None, Some(2), None],
+                ]
+            );
+        })
+    }
+}
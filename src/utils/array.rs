@@ -1,4 +1,4 @@
-use core::mem::{self, MaybeUninit};
+use core::mem::MaybeUninit;
 
 /// Extracts the values from an array of `MaybeUninit` containers.
 ///
@@ -14,9 +14,30 @@ pub(crate) unsafe fn array_assume_init<T, const N: usize>(array: [MaybeUninit<T>
     // * `MaybeUninit<T>` and T are guaranteed to have the same layout
     // * `MaybeUninit` does not drop, so there are no double-frees
     // And thus the conversion is safe
-    let ret = unsafe { (&array as *const _ as *const [T; N]).read() };
+    //
+    // `array` used to be passed to `mem::forget` here, back when this was a
+    // `const fn` and dropping it would have required a `~const Destruct`
+    // bound. Since `MaybeUninit` has no drop glue, dropping `array` after the
+    // read is a no-op, which the test below checks.
+    unsafe { (&array as *const _ as *const [T; N]).read() }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::rc::Rc;
 
-    // FIXME: required to avoid `~const Destruct` bound
-    mem::forget(array);
-    ret
+    #[test]
+    fn drops_each_value_once() {
+        let value = Rc::new(());
+        let array = [
+            MaybeUninit::new(Rc::clone(&value)),
+            MaybeUninit::new(Rc::clone(&value)),
+        ];
+        // SAFETY: every element was initialized above.
+        let values = unsafe { array_assume_init(array) };
+        assert_eq!(Rc::strong_count(&value), 3);
+        drop(values);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
 }
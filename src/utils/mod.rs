@@ -4,13 +4,18 @@ mod array;
 mod array_dequeue;
 mod pin;
 mod poll_state;
+pub(crate) mod size_hint;
 mod wakers;
 
 pub(crate) use array::array_assume_init;
 pub(crate) use array_dequeue::ArrayDequeue;
-pub(crate) use pin::{get_pin_mut, get_pin_mut_from_vec, iter_pin_mut, iter_pin_mut_vec};
+pub(crate) use pin::{get_pin_mut, iter_pin_mut};
+#[cfg(feature = "alloc")]
+pub(crate) use pin::{get_pin_mut_from_vec, iter_pin_mut_vec};
 pub(crate) use poll_state::PollState;
-pub(crate) use wakers::{dummy_waker, WakerArray, WakerVec};
+pub(crate) use wakers::WakerArray;
+#[cfg(feature = "alloc")]
+pub(crate) use wakers::WakerVec;
 
 #[cfg(test)]
 pub(crate) mod channel;
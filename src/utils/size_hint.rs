@@ -0,0 +1,67 @@
+//! Helpers to combine the size hints of substreams.
+
+/// The size hint of a stream which yields every item of the given streams.
+pub(crate) fn sum<I>(hints: I) -> (usize, Option<usize>)
+where
+    I: IntoIterator<Item = (usize, Option<usize>)>,
+{
+    hints
+        .into_iter()
+        .fold((0, Some(0)), |(low, high), (item_low, item_high)| {
+            let high = match (high, item_high) {
+                (Some(high), Some(item_high)) => high.checked_add(item_high),
+                _ => None,
+            };
+            (low.saturating_add(item_low), high)
+        })
+}
+
+/// The size hint of a stream which yields one item for every item of each of
+/// the given streams, ending with the shortest of them.
+pub(crate) fn min<I>(hints: I) -> (usize, Option<usize>)
+where
+    I: IntoIterator<Item = (usize, Option<usize>)>,
+{
+    hints
+        .into_iter()
+        .fold((usize::MAX, None), |(low, high), (item_low, item_high)| {
+            let high = match (high, item_high) {
+                (Some(high), Some(item_high)) => Some(high.min(item_high)),
+                (high, item_high) => high.or(item_high),
+            };
+            (low.min(item_low), high)
+        })
+}
+
+/// The size hint of a stream which has already produced `n` items that
+/// haven't been yielded yet.
+pub(crate) fn add((low, high): (usize, Option<usize>), n: usize) -> (usize, Option<usize>) {
+    (
+        low.saturating_add(n),
+        high.and_then(|high| high.checked_add(n)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_hints() {
+        assert_eq!(sum([]), (0, Some(0)));
+        assert_eq!(sum([(1, Some(2)), (3, Some(4))]), (4, Some(6)));
+        assert_eq!(sum([(1, Some(2)), (3, None)]), (4, None));
+        assert_eq!(
+            sum([(usize::MAX, Some(usize::MAX)), (1, Some(1))]),
+            (usize::MAX, None)
+        );
+    }
+
+    #[test]
+    fn min_hints() {
+        assert_eq!(min([]), (usize::MAX, None));
+        assert_eq!(min([(1, Some(5)), (3, Some(4))]), (1, Some(4)));
+        assert_eq!(min([(1, None), (3, Some(4))]), (1, Some(4)));
+        assert_eq!(min([(1, None), (3, None)]), (1, None));
+    }
+}
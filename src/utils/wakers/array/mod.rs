@@ -1,4 +1,179 @@
-mod awakeness;
+mod poll_all;
+#[cfg(feature = "alloc")]
 mod waker_array;
 
-pub(crate) use waker_array::WakerArray;
+#[cfg(feature = "alloc")]
+use super::readiness::Awakeness;
+
+use core::task::Waker;
+
+/// Joins of up to this many subfutures hand every subfuture the parent's waker
+/// instead of allocating shared per-index wakers, see [`WakerArray::for_join`].
+///
+/// For so few subfutures, polling all of them on every wakeup is cheaper than
+/// the allocation needed to tell them apart. Without an allocator this mode is
+/// used for every combinator and length.
+#[cfg(feature = "alloc")]
+const POLL_ALL_MAX_LEN: usize = 4;
+
+/// A collection of wakers for a fixed number of subfutures.
+pub(crate) struct WakerArray<const N: usize>(Repr<N>);
+
+enum Repr<const N: usize> {
+    PollAll(poll_all::WakerArray<N>),
+    #[cfg(feature = "alloc")]
+    Shared(waker_array::WakerArray<N>),
+}
+
+impl<const N: usize> WakerArray<N> {
+    /// Create a new instance of `WakerArray`.
+    pub(crate) fn new() -> Self {
+        #[cfg(feature = "alloc")]
+        return Self(Repr::Shared(waker_array::WakerArray::new()));
+        #[cfg(not(feature = "alloc"))]
+        Self(Repr::PollAll(poll_all::WakerArray::new()))
+    }
+
+    /// Create a new instance of `WakerArray` for a combinator which waits for
+    /// every subfuture to complete.
+    ///
+    /// Such a combinator has to poll every subfuture to completion anyway, so
+    /// for small lengths the subfutures aren't told apart, and all of them are
+    /// polled on every wakeup.
+    pub(crate) fn for_join() -> Self {
+        #[cfg(feature = "alloc")]
+        if N > POLL_ALL_MAX_LEN {
+            return Self::new();
+        }
+        Self(Repr::PollAll(poll_all::WakerArray::new()))
+    }
+
+    pub(crate) fn get(&self, index: usize) -> Option<&Waker> {
+        match &self.0 {
+            Repr::PollAll(wakers) => wakers.get(index),
+            #[cfg(feature = "alloc")]
+            Repr::Shared(wakers) => wakers.get(index),
+        }
+    }
+
+    pub(crate) fn awakeness(&mut self) -> AwakenessArray<'_, N> {
+        match &mut self.0 {
+            Repr::PollAll(wakers) => AwakenessArray::PollAll(wakers.awakeness()),
+            #[cfg(feature = "alloc")]
+            Repr::Shared(wakers) => AwakenessArray::Shared(wakers.awakeness()),
+        }
+    }
+}
+
+/// The indices woken since the last poll, see [`WakerArray::awakeness`].
+pub(crate) enum AwakenessArray<'a, const N: usize> {
+    PollAll(&'a mut poll_all::Awakeness<N>),
+    #[cfg(feature = "alloc")]
+    Shared(Awakeness<'a>),
+}
+
+impl<const N: usize> AwakenessArray<'_, N> {
+    pub(crate) fn set_parent_waker(&mut self, waker: &Waker) {
+        match self {
+            Self::PollAll(awakeness) => awakeness.set_parent_waker(waker),
+            #[cfg(feature = "alloc")]
+            Self::Shared(awakeness) => awakeness.set_parent_waker(waker),
+        }
+    }
+
+    pub(crate) fn awake_list(&self) -> &[usize] {
+        match self {
+            Self::PollAll(awakeness) => awakeness.awake_list(),
+            #[cfg(feature = "alloc")]
+            Self::Shared(awakeness) => awakeness.awake_list(),
+        }
+    }
+
+    pub(crate) fn awake_list_mut(&mut self) -> &mut [usize] {
+        match self {
+            Self::PollAll(awakeness) => awakeness.awake_list_mut(),
+            #[cfg(feature = "alloc")]
+            Self::Shared(awakeness) => awakeness.awake_list_mut(),
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        match self {
+            Self::PollAll(awakeness) => awakeness.clear(),
+            #[cfg(feature = "alloc")]
+            Self::Shared(awakeness) => awakeness.clear(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::task::Wake;
+
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn small_joins_poll_all() {
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let parent = Waker::from(counter.clone());
+        let mut wa = WakerArray::<2>::for_join();
+        assert!(matches!(wa.0, Repr::PollAll(_)));
+
+        let mut awakeness = wa.awakeness();
+        awakeness.set_parent_waker(&parent);
+        awakeness.clear();
+        assert_eq!(awakeness.awake_list(), [0, 1]);
+
+        // The children wake the parent directly.
+        assert!(wa.get(1).unwrap().will_wake(&parent));
+        wa.get(1).unwrap().wake_by_ref();
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn large_joins_track_indices() {
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let parent = Waker::from(counter.clone());
+        let mut wa = WakerArray::<5>::for_join();
+        assert!(matches!(wa.0, Repr::Shared(_)));
+
+        let mut awakeness = wa.awakeness();
+        awakeness.set_parent_waker(&parent);
+        awakeness.clear();
+
+        wa.get(1).unwrap().wake_by_ref();
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+        let mut awakeness = wa.awakeness();
+        awakeness.set_parent_waker(&parent);
+        assert_eq!(awakeness.awake_list(), [1]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn small_arrays_track_indices() {
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let parent = Waker::from(counter.clone());
+        let mut wa = WakerArray::<2>::new();
+        assert!(matches!(wa.0, Repr::Shared(_)));
+
+        let mut awakeness = wa.awakeness();
+        awakeness.set_parent_waker(&parent);
+        awakeness.clear();
+
+        wa.get(1).unwrap().wake_by_ref();
+        let mut awakeness = wa.awakeness();
+        awakeness.set_parent_waker(&parent);
+        assert_eq!(awakeness.awake_list(), [1]);
+    }
+}
@@ -0,0 +1,55 @@
+use super::super::dummy_waker;
+
+use core::task::Waker;
+
+// Instead of pointing into shared state which records the woken indices, every
+// subfuture is handed a clone of the parent's waker, and every subfuture counts
+// as awake on every poll. This needs no allocation at all.
+
+/// A collection of wakers which all delegate to the parent waker.
+pub(crate) struct WakerArray<const N: usize> {
+    awakeness: Awakeness<N>,
+}
+
+impl<const N: usize> WakerArray<N> {
+    /// Create a new instance of `WakerArray`.
+    pub(crate) fn new() -> Self {
+        Self {
+            awakeness: Awakeness {
+                wakers: core::array::from_fn(|_| dummy_waker()),
+                awake_list: core::array::from_fn(core::convert::identity),
+            },
+        }
+    }
+
+    pub(crate) fn get(&self, index: usize) -> Option<&Waker> {
+        self.awakeness.wakers.get(index)
+    }
+
+    pub(crate) fn awakeness(&mut self) -> &mut Awakeness<N> {
+        &mut self.awakeness
+    }
+}
+
+pub(crate) struct Awakeness<const N: usize> {
+    wakers: [Waker; N],
+    /// Every index, since we can't tell which subfutures were woken.
+    awake_list: [usize; N],
+}
+
+impl<const N: usize> Awakeness<N> {
+    pub(crate) fn set_parent_waker(&mut self, waker: &Waker) {
+        for child in self.wakers.iter_mut() {
+            if !child.will_wake(waker) {
+                *child = waker.clone();
+            }
+        }
+    }
+    pub(crate) fn awake_list(&self) -> &[usize] {
+        &self.awake_list
+    }
+    pub(crate) fn awake_list_mut(&mut self) -> &mut [usize] {
+        &mut self.awake_list
+    }
+    pub(crate) fn clear(&mut self) {}
+}
@@ -1,22 +1,10 @@
+use super::super::readiness::{Awakeness, Readiness};
 use super::super::shared_slice_waker::{waker_from_position, WakerArrayTrait};
-use super::awakeness::AwakenessArray;
+use super::super::sync::Arc;
 
+use alloc::vec::Vec;
 use core::array;
 use core::task::Waker;
-use std::sync::Arc;
-use std::sync::Mutex;
-use std::sync::MutexGuard;
-
-use bitvec::prelude::BitArray;
-use bitvec::slice::BitSlice;
-
-struct BitArrayWrapped<const N: usize>(BitArray<[u8; N]>);
-
-impl<const N: usize> AsMut<BitSlice<u8>> for BitArrayWrapped<N> {
-    fn as_mut(&mut self) -> &mut BitSlice<u8> {
-        &mut self.0[..N]
-    }
-}
 
 // Each waker points to a slot in the `wake_data` part of `Inner`.
 // Every one of those slots contain a pointer to the Arc wrapping `Inner` itself.
@@ -49,18 +37,27 @@ impl<const N: usize> AsMut<BitSlice<u8>> for BitArrayWrapped<N> {
 pub(crate) struct WakerArray<const N: usize> {
     inner: Arc<WakerArrayInner<N>>,
     wakers: [Waker; N],
+    /// Buffer for the indices collected from the readiness.
+    awake_list: Vec<usize>,
 }
 struct WakerArrayInner<const N: usize> {
     wake_data: [*const Self; N],
-    awakeness: Mutex<AwakenessArray<N>>,
+    readiness: Readiness,
 }
 
+// SAFETY: `wake_data` is written once, before any waker is handed out, and
+// only ever points back at the `Arc` holding this value. The wakers only read
+// those pointers, and all other shared state lives in the `Readiness`, which
+// is synchronized.
+unsafe impl<const N: usize> Send for WakerArrayInner<N> {}
+unsafe impl<const N: usize> Sync for WakerArrayInner<N> {}
+
 impl<const N: usize> WakerArray<N> {
     /// Create a new instance of `WakerArray`.
     pub(crate) fn new() -> Self {
         let mut inner = Arc::new(WakerArrayInner {
-            awakeness: Mutex::new(AwakenessArray::new()),
-            wake_data: [std::ptr::null(); N], // We don't know the Arc's address yet so put null for now.
+            readiness: Readiness::new(N),
+            wake_data: [core::ptr::null(); N], // We don't know the Arc's address yet so put null for now.
         });
         let raw = Arc::into_raw(Arc::clone(&inner)); // The Arc's address.
 
@@ -78,15 +75,19 @@ impl<const N: usize> WakerArray<N> {
                 waker_from_position::<WakerArrayInner<N>>(data as *const *const WakerArrayInner<N>)
             }
         });
-        Self { inner, wakers }
+        Self {
+            inner,
+            wakers,
+            awake_list: Vec::with_capacity(N),
+        }
     }
 
     pub(crate) fn get(&self, index: usize) -> Option<&Waker> {
         self.wakers.get(index)
     }
 
-    pub(crate) fn awakeness(&mut self) -> MutexGuard<'_, AwakenessArray<N>> {
-        self.inner.awakeness.lock().unwrap()
+    pub(crate) fn awakeness(&mut self) -> Awakeness<'_> {
+        Awakeness::new(&self.inner.readiness, &mut self.awake_list)
     }
 }
 
@@ -96,7 +97,7 @@ impl<const N: usize> WakerArrayTrait for WakerArrayInner<N> {
     }
 
     fn wake_index(&self, index: usize) {
-        self.awakeness.lock().unwrap().wake(index);
+        self.readiness.wake(index);
     }
 }
 
@@ -139,3 +140,74 @@ mod tests {
         assert_eq!(Arc::strong_count(&wa.inner), 1);
     }
 }
+
+#[cfg(all(test, futures_concurrency_loom))]
+mod loom_tests {
+    use super::super::super::sync::model::counting_waker;
+    use super::*;
+
+    use loom::thread;
+
+    /// Take the indices which have been woken since the last call.
+    fn drain<const N: usize>(wa: &mut WakerArray<N>, parent: &Waker) -> Vec<usize> {
+        let mut awakeness = wa.awakeness();
+        awakeness.set_parent_waker(parent);
+        let list = awakeness.awake_list().clone();
+        awakeness.clear();
+        list
+    }
+
+    #[test]
+    fn loom_wake_races_awakeness() {
+        loom::model(|| {
+            let mut wa = WakerArray::<2>::new();
+            let (parent, counter) = counting_waker();
+            drain(&mut wa, &parent);
+
+            let w0 = wa.get(0).unwrap().clone();
+            let w1 = wa.get(1).unwrap().clone();
+            let t0 = thread::spawn(move || w0.wake());
+            let t1 = thread::spawn(move || {
+                w1.wake_by_ref();
+                drop(w1);
+            });
+
+            let woken_before = counter.count();
+            let mut seen = drain(&mut wa, &parent);
+            t0.join().unwrap();
+            t1.join().unwrap();
+
+            // Any index missed by the concurrent drain must have woken the
+            // parent, so the task gets polled again to pick it up.
+            let missed = drain(&mut wa, &parent);
+            if !missed.is_empty() {
+                assert!(counter.count() > woken_before);
+            }
+            seen.extend(missed);
+            seen.sort_unstable();
+            assert_eq!(seen, [0, 1]);
+            assert_eq!(Arc::strong_count(&wa.inner), 3);
+        });
+    }
+
+    #[test]
+    fn loom_clone_and_drop_race() {
+        loom::model(|| {
+            let mut wa = WakerArray::<1>::new();
+            let (parent, _counter) = counting_waker();
+            drain(&mut wa, &parent);
+
+            let waker = wa.get(0).unwrap().clone();
+            let t = thread::spawn(move || {
+                let cloned = waker.clone();
+                drop(waker);
+                cloned.wake_by_ref();
+                cloned.wake();
+            });
+            // Dropping the array while the other thread still holds wakers
+            // must keep the shared allocation alive until the last one goes.
+            drop(wa);
+            t.join().unwrap();
+        });
+    }
+}
@@ -0,0 +1,192 @@
+use super::sync::{AtomicUsize, Ordering};
+
+use core::cell::UnsafeCell;
+use core::task::Waker;
+
+/// Nobody is touching the waker.
+const WAITING: usize = 0;
+/// The parent is registering a new waker.
+const REGISTERING: usize = 0b01;
+/// A child is taking the waker to wake it.
+const WAKING: usize = 0b10;
+
+/// Holds the parent's waker, so children can wake it from any thread.
+///
+/// This follows the protocol of `futures::task::AtomicWaker`: instead of
+/// waiting for each other, the parent registering and a child waking each
+/// claim the waker with a state bit. A child which finds the parent
+/// registering just leaves its bit set, and the parent wakes the new waker
+/// itself once it's done. So nobody ever spins, and no waker code runs while
+/// anyone else has to wait for it.
+pub(crate) struct AtomicWaker {
+    state: AtomicUsize,
+    waker: UnsafeCell<Option<Waker>>,
+}
+
+// SAFETY: `waker` is only accessed by whoever moved `state` away from
+// `WAITING`, see the methods below.
+unsafe impl Send for AtomicWaker {}
+unsafe impl Sync for AtomicWaker {}
+
+impl AtomicWaker {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: AtomicUsize::new(WAITING),
+            waker: UnsafeCell::new(None),
+        }
+    }
+
+    /// Register the waker to wake, replacing the previous one.
+    ///
+    /// Must not be called concurrently with itself, which holds since only
+    /// the parent registers, from its `poll`.
+    pub(crate) fn register(&self, waker: &Waker) {
+        match self.state.compare_exchange(
+            WAITING,
+            REGISTERING,
+            Ordering::Acquire,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => {
+                // SAFETY: we've set `REGISTERING`, so we have exclusive access
+                // until we reset the state. Children which wake meanwhile only
+                // set `WAKING`.
+                let old = unsafe {
+                    let slot = &mut *self.waker.get();
+                    match slot {
+                        Some(old) if old.will_wake(waker) => None,
+                        _ => slot.replace(waker.clone()),
+                    }
+                };
+
+                let woken = match self.state.compare_exchange(
+                    REGISTERING,
+                    WAITING,
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                ) {
+                    Ok(_) => None,
+                    Err(_) => {
+                        // A child woke us while we were registering. We still
+                        // have exclusive access, so take the waker back out and
+                        // wake it on the child's behalf.
+                        // SAFETY: only we can reset the state from here.
+                        let woken = unsafe { (*self.waker.get()).take() };
+                        self.state.swap(WAITING, Ordering::AcqRel);
+                        woken
+                    }
+                };
+
+                // Only run waker code once we've released the state.
+                drop(old);
+                if let Some(woken) = woken {
+                    woken.wake();
+                }
+            }
+            // A child is in the middle of waking the previous waker. Wake the
+            // new one as well, so the wakeup can't get lost.
+            Err(WAKING) => waker.wake_by_ref(),
+            // Another registration is in progress. Only the parent registers,
+            // so this can't happen.
+            Err(state) => debug_assert!(state & REGISTERING != 0),
+        }
+    }
+
+    /// Take the registered waker out, if any and if nobody else is using it.
+    ///
+    /// Returns `None` while the parent is registering, in which case the
+    /// parent wakes the new waker itself.
+    pub(crate) fn take(&self) -> Option<Waker> {
+        match self.state.fetch_or(WAKING, Ordering::AcqRel) {
+            WAITING => {
+                // SAFETY: we've set `WAKING` while the state was `WAITING`, so
+                // we have exclusive access until we clear it again.
+                let waker = unsafe { (*self.waker.get()).take() };
+                self.state.fetch_and(!WAKING, Ordering::Release);
+                waker
+            }
+            _ => None,
+        }
+    }
+
+    /// Wake the registered waker, if any.
+    pub(crate) fn wake(&self) {
+        if let Some(waker) = self.take() {
+            waker.wake();
+        }
+    }
+}
+
+#[cfg(all(test, not(futures_concurrency_loom)))]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+    use std::task::Wake;
+
+    /// A waker which registers itself again when it is woken, like a task
+    /// which is polled from within its waker.
+    struct Reentrant(AtomicWaker, AtomicUsize);
+
+    impl Wake for Reentrant {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.1.fetch_add(1, Ordering::SeqCst);
+            self.0.register(&Waker::from(self.clone()));
+        }
+    }
+
+    #[test]
+    fn reentrant_wake() {
+        let reentrant = Arc::new(Reentrant(AtomicWaker::new(), AtomicUsize::new(0)));
+        let waker = Waker::from(reentrant.clone());
+        reentrant.0.register(&waker);
+
+        // The waker registers itself again while being woken, which would
+        // deadlock if waking held a lock.
+        reentrant.0.wake();
+        assert_eq!(reentrant.1.load(Ordering::SeqCst), 1);
+        reentrant.0.wake();
+        assert_eq!(reentrant.1.load(Ordering::SeqCst), 2);
+    }
+}
+
+#[cfg(all(test, futures_concurrency_loom))]
+mod loom_tests {
+    use super::super::sync::model::counting_waker;
+    use super::super::sync::Arc;
+    use super::*;
+
+    use loom::thread;
+
+    /// A wake racing the parent registering a new waker wakes the old or the
+    /// new waker, and doesn't lose the new one.
+    #[test]
+    fn loom_wake_races_register() {
+        loom::model(|| {
+            let atomic_waker = Arc::new(AtomicWaker::new());
+            let (old, old_counter) = counting_waker();
+            let (new, new_counter) = counting_waker();
+            atomic_waker.register(&old);
+
+            let handle = {
+                let atomic_waker = atomic_waker.clone();
+                thread::spawn(move || atomic_waker.wake())
+            };
+            atomic_waker.register(&new);
+            handle.join().unwrap();
+
+            // If the wake took the old waker while we were registering, the new
+            // one is woken as well, just in case.
+            assert!(old_counter.count() + new_counter.count() >= 1);
+            if new_counter.count() == 0 {
+                // The new waker is still registered.
+                atomic_waker.wake();
+                assert_eq!(new_counter.count(), 1);
+            }
+        });
+    }
+}
@@ -1,8 +1,17 @@
 mod array;
+#[cfg(feature = "alloc")]
+mod atomic_waker;
 mod dummy;
+#[cfg(feature = "alloc")]
+mod readiness;
+#[cfg(feature = "alloc")]
 mod shared_slice_waker;
+#[cfg(feature = "alloc")]
+mod sync;
+#[cfg(feature = "alloc")]
 mod vec;
 
 pub(crate) use array::*;
 pub(crate) use dummy::dummy_waker;
+#[cfg(feature = "alloc")]
 pub(crate) use vec::*;
@@ -0,0 +1,280 @@
+use super::atomic_waker::AtomicWaker;
+use super::sync::{AtomicBool, AtomicUsize, Ordering};
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::task::Waker;
+
+const BITS: usize = usize::BITS as usize;
+
+/// Tracks which of a set of wakers have been woken, and in which order,
+/// without taking a lock on the hot path.
+///
+/// Waking a child sets its bit in an atomic bitset. Only the wake which sets
+/// the bit goes on to push the index onto a queue, so every child is queued at
+/// most once, and the queue never holds more than one entry per child. The
+/// parent pops the queue in order, clearing the bits as it goes.
+///
+/// Only the first child woken after the parent last collected the queue goes
+/// on to wake the parent; the `notified` flag records whether that has
+/// happened yet.
+pub(crate) struct Readiness {
+    awake_set: Box<[AtomicUsize]>,
+    /// Ring buffer of the woken indices, each stored plus one. A zero marks a
+    /// slot which hasn't been written yet.
+    queue: Box<[AtomicUsize]>,
+    /// Position of the next slot to pop. Only touched by the parent.
+    head: AtomicUsize,
+    /// Position of the next slot to push to.
+    tail: AtomicUsize,
+    /// Whether the parent has been woken since it last collected the queue.
+    notified: AtomicBool,
+    /// Registered by the parent on every poll, and taken by the first child
+    /// to wake it after that.
+    parent_waker: AtomicWaker,
+}
+
+impl Readiness {
+    /// Create the readiness for `len` children, which all start out awake.
+    pub(crate) fn new(len: usize) -> Self {
+        let awake_set = (0..len.div_ceil(BITS))
+            .map(|word| match len - word * BITS {
+                n if n >= BITS => AtomicUsize::new(usize::MAX),
+                n => AtomicUsize::new((1 << n) - 1),
+            })
+            .collect();
+        let queue = (0..len).map(|index| AtomicUsize::new(index + 1)).collect();
+        Self {
+            awake_set,
+            queue,
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(len),
+            // Everything is already awake, so there is nothing to notify.
+            notified: AtomicBool::new(true),
+            parent_waker: AtomicWaker::new(),
+        }
+    }
+
+    /// Mark the child at `index` as awake, waking the parent if needed.
+    pub(crate) fn wake(&self, index: usize) {
+        let bit = 1 << (index % BITS);
+        let prev = self.awake_set[index / BITS].fetch_or(bit, Ordering::SeqCst);
+        if prev & bit != 0 {
+            // The child was already awake. Whoever set the bit is responsible
+            // for queueing it, and notifying the parent.
+            return;
+        }
+        // Each child has at most one entry in the queue, so the slot we get
+        // has always been popped already.
+        let position = self.tail.fetch_add(1, Ordering::SeqCst);
+        let prev = self.queue[position % self.queue.len()].swap(index + 1, Ordering::SeqCst);
+        debug_assert_eq!(prev, 0, "queue slot should have been popped");
+
+        if !self.notified.swap(true, Ordering::SeqCst) {
+            self.parent_waker.wake();
+        }
+    }
+
+    pub(crate) fn set_parent_waker(&self, waker: &Waker) {
+        self.parent_waker.register(waker);
+    }
+
+    /// Take the parent's waker out, so it won't be woken through us anymore.
+    pub(crate) fn take_parent_waker(&self) -> Option<Waker> {
+        self.parent_waker.take()
+    }
+
+    /// Move the indices of all children woken since the last call into
+    /// `awake_list`, in the order they were woken.
+    fn collect_into(&self, awake_list: &mut Vec<usize>) {
+        // Reset the flag _before_ looking at the queue. A wake which queues
+        // its index too late for us to see is then guaranteed to see the reset
+        // flag, and wake the parent.
+        self.notified.store(false, Ordering::SeqCst);
+
+        let mut head = self.head.load(Ordering::Relaxed);
+        let tail = self.tail.load(Ordering::SeqCst);
+        while head != tail {
+            let slot = &self.queue[head % self.queue.len()];
+            // The slot was claimed, but the wake hasn't written to it yet.
+            // Stop here to keep the order, that wake will notify the parent.
+            let index = match slot.swap(0, Ordering::SeqCst) {
+                0 => break,
+                entry => entry - 1,
+            };
+            // Clear the bit only after popping the entry, so the child can't
+            // be queued twice.
+            self.awake_set[index / BITS].fetch_and(!(1 << (index % BITS)), Ordering::SeqCst);
+            awake_list.push(index);
+            head = head.wrapping_add(1);
+        }
+        self.head.store(head, Ordering::Relaxed);
+    }
+}
+
+/// A view into the readiness of a set of wakers, used by the parent while it
+/// polls.
+pub(crate) struct Awakeness<'a> {
+    readiness: &'a Readiness,
+    awake_list: &'a mut Vec<usize>,
+}
+
+impl<'a> Awakeness<'a> {
+    pub(crate) fn new(readiness: &'a Readiness, awake_list: &'a mut Vec<usize>) -> Self {
+        Self {
+            readiness,
+            awake_list,
+        }
+    }
+
+    /// Register the parent's waker, and collect the children which were woken
+    /// since the last time.
+    ///
+    /// The waker is registered first, so that a child woken in between can't
+    /// wake a stale waker.
+    pub(crate) fn set_parent_waker(&mut self, waker: &Waker) {
+        self.readiness.set_parent_waker(waker);
+        self.readiness.collect_into(self.awake_list);
+    }
+
+    pub(crate) fn awake_list(&self) -> &Vec<usize> {
+        self.awake_list
+    }
+
+    pub(crate) fn awake_list_mut(&mut self) -> &mut [usize] {
+        self.awake_list
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.awake_list.clear();
+    }
+}
+
+#[cfg(all(test, not(futures_concurrency_loom)))]
+mod tests {
+    use super::*;
+    use crate::utils::wakers::dummy_waker;
+
+    fn collect(readiness: &Readiness) -> Vec<usize> {
+        let mut awake_list = vec![];
+        Awakeness::new(readiness, &mut awake_list).set_parent_waker(&dummy_waker());
+        awake_list
+    }
+
+    #[test]
+    fn starts_awake() {
+        let readiness = Readiness::new(BITS + 2);
+        assert_eq!(collect(&readiness), (0..BITS + 2).collect::<Vec<_>>());
+        assert_eq!(collect(&readiness), vec![]);
+    }
+
+    #[test]
+    fn wakes_across_words() {
+        let readiness = Readiness::new(BITS * 2);
+        collect(&readiness);
+        readiness.wake(BITS + 1);
+        readiness.wake(3);
+        readiness.wake(BITS + 1);
+        assert_eq!(collect(&readiness), vec![BITS + 1, 3]);
+    }
+
+    #[test]
+    fn keeps_wake_order() {
+        let readiness = Readiness::new(8);
+        collect(&readiness);
+        readiness.wake(5);
+        readiness.wake(1);
+        readiness.wake(5);
+        readiness.wake(3);
+        assert_eq!(collect(&readiness), vec![5, 1, 3]);
+
+        // The queue wraps around.
+        for index in (0..8).rev() {
+            readiness.wake(index);
+        }
+        assert_eq!(collect(&readiness), (0..8).rev().collect::<Vec<_>>());
+    }
+}
+
+#[cfg(all(test, futures_concurrency_loom))]
+mod loom_tests {
+    use super::super::sync::model::counting_waker;
+    use super::super::sync::Arc;
+    use super::*;
+
+    use loom::thread;
+
+    /// Children woken concurrently with the parent collecting the awake list
+    /// are either collected, or the parent is woken so it collects them next
+    /// time.
+    #[test]
+    fn loom_no_lost_wakeups() {
+        loom::model(|| {
+            let readiness = Arc::new(Readiness::new(2));
+            let (waker, counter) = counting_waker();
+            let mut awake_list = vec![];
+
+            // Collect the initial state, so every child is asleep.
+            Awakeness::new(&readiness, &mut awake_list).set_parent_waker(&waker);
+            awake_list.clear();
+
+            let handles: Vec<_> = (0..2)
+                .map(|index| {
+                    let readiness = readiness.clone();
+                    thread::spawn(move || readiness.wake(index))
+                })
+                .collect();
+
+            // Poll once, concurrently with the wakes. Executors reschedule a
+            // task which is woken while it is being polled, so wakes from here
+            // on count.
+            let woken_before = counter.count();
+            Awakeness::new(&readiness, &mut awake_list).set_parent_waker(&waker);
+
+            for handle in handles {
+                handle.join().unwrap();
+            }
+
+            // If the poll missed any child, the parent must have been woken
+            // since that poll started, so it polls again to pick it up.
+            let mut missed = vec![];
+            Awakeness::new(&readiness, &mut missed).set_parent_waker(&waker);
+            if !missed.is_empty() {
+                assert!(counter.count() > woken_before);
+            }
+
+            awake_list.extend(missed);
+            awake_list.sort_unstable();
+            assert_eq!(awake_list, vec![0, 1]);
+        });
+    }
+
+    /// A child woken repeatedly while the parent is collecting still ends up
+    /// in the awake list exactly once per collection.
+    #[test]
+    fn loom_repeated_wake() {
+        loom::model(|| {
+            let readiness = Arc::new(Readiness::new(1));
+            let (waker, counter) = counting_waker();
+            let mut awake_list = vec![];
+            Awakeness::new(&readiness, &mut awake_list).set_parent_waker(&waker);
+            awake_list.clear();
+
+            let handle = {
+                let readiness = readiness.clone();
+                thread::spawn(move || {
+                    readiness.wake(0);
+                    readiness.wake(0);
+                })
+            };
+
+            Awakeness::new(&readiness, &mut awake_list).set_parent_waker(&waker);
+            handle.join().unwrap();
+            Awakeness::new(&readiness, &mut awake_list).set_parent_waker(&waker);
+
+            assert!(!awake_list.is_empty());
+            assert!(awake_list.len() <= 2);
+            assert!(counter.count() >= 1);
+        });
+    }
+}
@@ -1,5 +1,6 @@
+use super::sync::Arc;
+
 use core::task::{RawWaker, RawWakerVTable, Waker};
-use std::sync::Arc;
 
 pub(super) trait WakerArrayTrait {
     fn get_wake_data_slice(&self) -> &[*const Self];
@@ -28,23 +29,23 @@ pub(super) unsafe fn waker_from_position<A: WakerArrayTrait>(pointer: *const *co
         // Calculate the index
         let index = ((pointer as usize) // This is the slot our pointer points to.
             - (arc.get_wake_data_slice() as *const [*const A] as *const () as usize)) // This is the starting address of wake_data.
-            / std::mem::size_of::<*const A>();
+            / core::mem::size_of::<*const A>();
 
         arc.wake_index(index);
 
         // Dropping the Arc would decrement the strong count.
         // We only want to do that when we're not waking by ref.
         if BY_REF {
-            std::mem::forget(arc);
+            core::mem::forget(arc);
         } else {
-            std::mem::drop(arc);
+            core::mem::drop(arc);
         }
     }
     unsafe fn drop_waker<A: WakerArrayTrait>(pointer: *const ()) {
         let pointer = pointer as *const *const A;
         let arc = to_arc::<A>(pointer);
         // Decrement the strong count by dropping the Arc.
-        std::mem::drop(arc);
+        core::mem::drop(arc);
     }
     fn create_vtable<A: WakerArrayTrait>() -> &'static RawWakerVTable {
         &RawWakerVTable::new(
@@ -0,0 +1,52 @@
+//! Synchronization primitives used by the wakers.
+//!
+//! When built with `--cfg futures_concurrency_loom` these are swapped for
+//! loom's versions, so the concurrency model tests can explore every
+//! interleaving. Exploring every interleaving of the two-thread models takes
+//! too long, so bound the number of preemptions when running them:
+//!
+//! ```text
+//! LOOM_MAX_PREEMPTIONS=4 RUSTFLAGS="--cfg futures_concurrency_loom" cargo test --lib --release loom
+//! ```
+
+#[cfg(futures_concurrency_loom)]
+pub(crate) use loom::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[cfg(futures_concurrency_loom)]
+pub(crate) use loom::sync::Arc;
+
+#[cfg(not(futures_concurrency_loom))]
+pub(crate) use alloc::sync::Arc;
+#[cfg(not(futures_concurrency_loom))]
+pub(crate) use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Helpers for the loom model tests.
+#[cfg(all(test, futures_concurrency_loom))]
+pub(crate) mod model {
+    use super::{AtomicUsize, Ordering};
+    use core::task::Waker;
+    use std::task::Wake;
+
+    /// A waker which counts how many times it was woken.
+    pub(crate) struct CountingWaker(AtomicUsize);
+
+    impl CountingWaker {
+        pub(crate) fn count(&self) -> usize {
+            self.0.load(Ordering::SeqCst)
+        }
+    }
+
+    impl Wake for CountingWaker {
+        fn wake(self: std::sync::Arc<Self>) {
+            self.wake_by_ref();
+        }
+
+        fn wake_by_ref(self: &std::sync::Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    pub(crate) fn counting_waker() -> (Waker, std::sync::Arc<CountingWaker>) {
+        let counter = std::sync::Arc::new(CountingWaker(AtomicUsize::new(0)));
+        (Waker::from(counter.clone()), counter)
+    }
+}
@@ -1,4 +1,3 @@
-mod awakeness;
 mod waker_vec;
 
 pub(crate) use waker_vec::WakerVec;
@@ -1,28 +1,33 @@
+use super::super::readiness::{Awakeness, Readiness};
 use super::super::shared_slice_waker::{waker_from_position, WakerArrayTrait};
-use super::awakeness::AwakenessVec;
+use super::super::sync::Arc;
 
+use alloc::vec::Vec;
 use core::task::Waker;
-use std::sync::Arc;
-use std::sync::Mutex;
-use std::sync::MutexGuard;
 
 /// A collection of wakers which delegate to an in-line waker.
 pub(crate) struct WakerVec {
     inner: Arc<WakerVecInner>,
     wakers: Vec<Waker>,
+    /// Buffer for the indices collected from the readiness.
+    awake_list: Vec<usize>,
 }
 
 struct WakerVecInner {
     wake_data: Vec<*const Self>,
-    awakeness: Mutex<AwakenessVec>,
+    readiness: Readiness,
 }
 
+// SAFETY: see the `WakerArrayInner` impls, the same reasoning applies.
+unsafe impl Send for WakerVecInner {}
+unsafe impl Sync for WakerVecInner {}
+
 impl WakerVec {
     /// Create a new instance of `WakerVec`.
     pub(crate) fn new(len: usize) -> Self {
         let mut inner = Arc::new(WakerVecInner {
-            awakeness: Mutex::new(AwakenessVec::new(len)),
-            wake_data: vec![std::ptr::null(); len],
+            readiness: Readiness::new(len),
+            wake_data: alloc::vec![core::ptr::null(); len],
         });
         let raw = Arc::into_raw(Arc::clone(&inner));
         unsafe { Arc::decrement_strong_count(raw) }
@@ -38,15 +43,44 @@ impl WakerVec {
                 waker_from_position::<WakerVecInner>(data as *const *const WakerVecInner)
             })
             .collect();
-        Self { inner, wakers }
+        Self {
+            inner,
+            wakers,
+            awake_list: Vec::with_capacity(len),
+        }
     }
 
     pub(crate) fn get(&self, index: usize) -> Option<&Waker> {
         self.wakers.get(index)
     }
 
-    pub(crate) fn awakeness(&mut self) -> MutexGuard<'_, AwakenessVec> {
-        self.inner.awakeness.lock().unwrap()
+    /// Returns the number of wakers in the collection.
+    pub(crate) fn len(&self) -> usize {
+        self.wakers.len()
+    }
+
+    /// Grow the collection so that it holds at least `len` wakers.
+    ///
+    /// Wakers that were handed out before still point into the old allocation,
+    /// so every index starts out awake in the new one. That way no wakeup can
+    /// get lost in between.
+    ///
+    /// Since the indices are already awake, waking them won't notify the
+    /// parent. So we wake the parent here instead, or it might stay parked.
+    pub(crate) fn grow(&mut self, len: usize) {
+        if len <= self.len() {
+            return;
+        }
+        let parent_waker = self.inner.readiness.take_parent_waker();
+        *self = Self::new(len);
+        if let Some(parent_waker) = parent_waker {
+            self.inner.readiness.set_parent_waker(&parent_waker);
+            parent_waker.wake();
+        }
+    }
+
+    pub(crate) fn awakeness(&mut self) -> Awakeness<'_> {
+        Awakeness::new(&self.inner.readiness, &mut self.awake_list)
     }
 }
 
@@ -56,6 +90,40 @@ impl WakerArrayTrait for WakerVecInner {
     }
 
     fn wake_index(&self, index: usize) {
-        self.awakeness.lock().unwrap().wake(index)
+        self.readiness.wake(index)
+    }
+}
+
+#[cfg(all(test, futures_concurrency_loom))]
+mod loom_tests {
+    use super::super::super::sync::model::counting_waker;
+    use super::*;
+
+    use loom::thread;
+
+    #[test]
+    fn loom_stale_wake_races_grow() {
+        loom::model(|| {
+            let mut wv = WakerVec::new(1);
+            let (parent, counter) = counting_waker();
+            {
+                let mut awakeness = wv.awakeness();
+                awakeness.set_parent_waker(&parent);
+                awakeness.clear();
+            }
+
+            let stale = wv.get(0).unwrap().clone();
+            let t = thread::spawn(move || stale.wake());
+            wv.grow(2);
+            t.join().unwrap();
+
+            // The stale waker still reaches the parent, and every index of
+            // the grown vec starts out awake.
+            assert!(counter.count() >= 1);
+            let mut awakeness = wv.awakeness();
+            awakeness.set_parent_waker(&parent);
+            assert_eq!(awakeness.awake_list(), &vec![0, 1]);
+            awakeness.clear();
+        });
     }
 }
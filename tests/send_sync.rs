@@ -0,0 +1,276 @@
+//! Compile-time checks that the combinators are `Send` and `Sync` exactly when
+//! their children are.
+
+use futures_concurrency::future::{
+    FutureGroup, JoinTimeout, LocalTaskGroup, RaceTimeout, TaskGroup, TryJoinTimeout,
+};
+use futures_concurrency::prelude::*;
+use futures_concurrency::stream::StreamGroup;
+use futures_lite::stream;
+
+use std::future::{self, Pending, Ready};
+use std::rc::Rc;
+use std::time::Duration;
+
+fn is_send<T: Send>(_: &T) {}
+
+fn is_send_sync<T: Send + Sync>(_: &T) {}
+
+/// Fails to compile if the value is `Send` or `Sync`.
+///
+/// Both impls of each trait apply to types which implement the auto trait,
+/// which leaves the trait parameter ambiguous.
+macro_rules! assert_not_send_sync {
+    ($($value:expr),* $(,)?) => {$({
+        trait AmbiguousIfSend<A> {
+            fn check(&self) {}
+        }
+        impl<T: ?Sized> AmbiguousIfSend<()> for T {}
+        impl<T: ?Sized + Send> AmbiguousIfSend<u8> for T {}
+
+        trait AmbiguousIfSync<A> {
+            fn check(&self) {}
+        }
+        impl<T: ?Sized> AmbiguousIfSync<()> for T {}
+        impl<T: ?Sized + Sync> AmbiguousIfSync<u8> for T {}
+
+        let value = $value;
+        AmbiguousIfSend::check(&value);
+        AmbiguousIfSync::check(&value);
+    })*};
+}
+
+macro_rules! assert_send_sync {
+    ($($value:expr),* $(,)?) => {$(
+        is_send_sync(&$value);
+    )*};
+}
+
+fn fut() -> Ready<u8> {
+    future::ready(1)
+}
+
+fn rc_fut() -> Ready<Rc<u8>> {
+    future::ready(Rc::new(1))
+}
+
+fn res() -> Ready<Result<u8, u8>> {
+    future::ready(Ok(1))
+}
+
+fn rc_res() -> Ready<Result<Rc<u8>, u8>> {
+    future::ready(Ok(Rc::new(1)))
+}
+
+fn never(_: Duration) -> Pending<()> {
+    future::pending()
+}
+
+fn st() -> stream::Once<u8> {
+    stream::once(1)
+}
+
+fn rc_st() -> stream::Once<Rc<u8>> {
+    stream::once(Rc::new(1))
+}
+
+#[test]
+fn join() {
+    assert_send_sync!(
+        [fut(), fut()].join(),
+        [fut(), fut()].join().into_stream(),
+        std::array::from_fn::<_, 8, _>(|_| fut()).join(),
+        (fut(), fut()).join(),
+        vec![fut()].join(),
+        vec![fut()].join().into_stream(),
+        vec![fut()].join_limit(1),
+    );
+    assert_not_send_sync!(
+        [rc_fut(), rc_fut()].join(),
+        [rc_fut(), rc_fut()].join().into_stream(),
+        [rc_fut(), rc_fut(), rc_fut(), rc_fut(), rc_fut()].join(),
+        (fut(), rc_fut()).join(),
+        vec![rc_fut()].join(),
+        vec![rc_fut()].join().into_stream(),
+        vec![rc_fut()].join_limit(1),
+    );
+}
+
+#[test]
+fn try_join() {
+    assert_send_sync!(
+        [res(), res()].try_join(),
+        [res(), res()].try_join().into_stream(),
+        (res(), res()).try_join(),
+        vec![res()].try_join(),
+        vec![res()].try_join().into_stream(),
+        vec![res()].try_join_limit(1),
+    );
+    assert_not_send_sync!(
+        [rc_res(), rc_res()].try_join(),
+        [rc_res(), rc_res()].try_join().into_stream(),
+        (res(), rc_res()).try_join(),
+        vec![rc_res()].try_join(),
+        vec![rc_res()].try_join().into_stream(),
+        vec![rc_res()].try_join_limit(1),
+    );
+}
+
+#[test]
+fn race() {
+    assert_send_sync!(
+        [fut(), fut()].race(),
+        (fut(), fut()).race(),
+        vec![fut()].race(),
+        [res(), res()].race_ok(),
+        (res(), res()).race_ok(),
+        vec![res()].race_ok(),
+        [res(), res()].race_ok_staggered(never, Duration::ZERO),
+        vec![res()].race_ok_staggered(never, Duration::ZERO),
+    );
+    assert_not_send_sync!(
+        [rc_fut(), rc_fut()].race(),
+        (rc_fut(), rc_fut()).race(),
+        vec![rc_fut()].race(),
+        [rc_res(), rc_res()].race_ok(),
+        (rc_res(), rc_res()).race_ok(),
+        vec![rc_res()].race_ok(),
+        [rc_res(), rc_res()].race_ok_staggered(never, Duration::ZERO),
+        vec![rc_res()].race_ok_staggered(never, Duration::ZERO),
+    );
+}
+
+#[test]
+fn settle() {
+    assert_send_sync!(
+        [res(), res()].settle(),
+        (res(), res()).settle(),
+        vec![res()].settle(),
+    );
+    assert_not_send_sync!(
+        [rc_res(), rc_res()].settle(),
+        (res(), rc_res()).settle(),
+        vec![rc_res()].settle(),
+    );
+}
+
+#[test]
+fn timeout() {
+    assert_send_sync!(
+        [fut(), fut()].join_timeout(future::pending::<()>()),
+        vec![res()].try_join_timeout(future::pending::<()>()),
+        vec![fut()].race_timeout(future::pending::<()>()),
+    );
+    assert_not_send_sync!(
+        [rc_fut(), rc_fut()].join_timeout(future::pending::<()>()),
+        vec![rc_res()].try_join_timeout(future::pending::<()>()),
+        vec![rc_fut()].race_timeout(future::pending::<()>()),
+    );
+}
+
+#[test]
+fn groups() {
+    let mut futures = FutureGroup::new();
+    futures.insert(fut());
+    let mut streams = StreamGroup::new();
+    streams.insert(st());
+    assert_send_sync!(futures, streams.keyed());
+
+    let mut futures = FutureGroup::new();
+    futures.insert(rc_fut());
+    let mut streams = StreamGroup::new();
+    streams.insert(rc_st());
+    assert_not_send_sync!(futures, streams.keyed());
+
+    // Tasks must be `Send`, but aren't required to be `Sync`.
+    let tasks = TaskGroup::<u8, u8>::new();
+    tasks.spawn(res());
+    assert_send_sync!(tasks.spawner());
+    is_send(&tasks);
+
+    let tasks = LocalTaskGroup::<Rc<u8>, u8>::new();
+    tasks.spawn(rc_res());
+    assert_not_send_sync!(tasks.spawner(), tasks);
+}
+
+#[test]
+fn merge() {
+    assert_send_sync!(
+        [st(), st()].merge(),
+        std::array::from_fn::<_, 8, _>(|_| st()).merge(),
+        (st(), st()).merge(),
+        vec![st()].merge(),
+        [st(), st()].merge_biased(),
+        (st(), st()).merge_biased(),
+        vec![st()].merge_biased(),
+        (st(), st()).merge_indexed(),
+        [st(), st()].merge_weighted([1, 2]),
+        vec![st()].merge_weighted(vec![1]),
+        stream::once(fut()).merge_limit(1),
+    );
+    assert_not_send_sync!(
+        [rc_st(), rc_st()].merge(),
+        [rc_st(), rc_st(), rc_st(), rc_st(), rc_st()].merge(),
+        (rc_st(), rc_st()).merge(),
+        vec![rc_st()].merge(),
+        [rc_st(), rc_st()].merge_biased(),
+        (rc_st(), rc_st()).merge_biased(),
+        vec![rc_st()].merge_biased(),
+        (st(), rc_st()).merge_indexed(),
+        [rc_st(), rc_st()].merge_weighted([1, 2]),
+        vec![rc_st()].merge_weighted(vec![1]),
+        stream::once(rc_fut()).merge_limit(1),
+    );
+}
+
+#[test]
+fn zip() {
+    assert_send_sync!(
+        [st(), st()].zip(),
+        (st(), st()).zip(),
+        vec![st()].zip(),
+        [st(), st()].zip_longest(),
+        (st(), st()).zip_longest(),
+        vec![st()].zip_longest(),
+        [st(), st()].combine_latest(),
+        (st(), st()).combine_latest(),
+        vec![st()].combine_latest(),
+    );
+    assert_not_send_sync!(
+        [rc_st(), rc_st()].zip(),
+        (st(), rc_st()).zip(),
+        vec![rc_st()].zip(),
+        [rc_st(), rc_st()].zip_longest(),
+        (st(), rc_st()).zip_longest(),
+        vec![rc_st()].zip_longest(),
+        [rc_st(), rc_st()].combine_latest(),
+        (st(), rc_st()).combine_latest(),
+        vec![rc_st()].combine_latest(),
+    );
+}
+
+#[test]
+fn chain() {
+    assert_send_sync!(
+        [st(), st()].chain(),
+        (st(), st()).chain(),
+        vec![st()].chain(),
+        [st(), st()].chain_indexed(),
+        (st(), stream::once("a")).chain_indexed(),
+        vec![st()].chain_indexed(),
+        [st(), st()].interleave(),
+        (st(), st()).interleave(),
+        vec![st()].interleave(),
+    );
+    assert_not_send_sync!(
+        [rc_st(), rc_st()].chain(),
+        (rc_st(), rc_st()).chain(),
+        vec![rc_st()].chain(),
+        [rc_st(), rc_st()].chain_indexed(),
+        (st(), rc_st()).chain_indexed(),
+        vec![rc_st()].chain_indexed(),
+        [rc_st(), rc_st()].interleave(),
+        (rc_st(), rc_st()).interleave(),
+        vec![rc_st()].interleave(),
+    );
+}